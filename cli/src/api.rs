@@ -1,8 +1,185 @@
-use anyhow::Result;
-use serde::Deserialize;
+use crate::logging::{self, Level};
+use anyhow::{bail, Result};
+use chrono::NaiveDate;
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Give up instead of retrying when the server asks us to wait longer than this
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Longest response body kept in an [`ApiError::Status`]
+const MAX_ERROR_BODY: usize = 200;
+
+/// Errors returned by [`ApiClient`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ApiError {
+    /// The server did not answer within the request timeout
+    #[error("Request timed out after {}s", .0.as_secs())]
+    Timeout(Duration),
+
+    /// DNS lookup or TCP connection failed
+    #[error("Could not connect to {url}: {message}")]
+    Connect { url: String, message: String },
+
+    /// TLS handshake or certificate validation failed
+    #[error("TLS error: {0}")]
+    Tls(String),
+
+    /// HTTP 429 Too Many Requests
+    #[error("Rate limited by the server")]
+    RateLimited { retry_after: Option<Duration> },
+
+    /// Any other non-success HTTP status
+    #[error("HTTP {status}: {body}")]
+    Status { status: u16, body: String },
+
+    /// The response body did not match the expected JSON schema
+    #[error("Unexpected response format: {0}")]
+    Decode(String),
+
+    /// Any other transport failure
+    #[error("Request failed: {0}")]
+    Request(String),
+}
+
+impl ApiError {
+    /// Short headline for error screens
+    pub fn title(&self) -> &'static str {
+        match self {
+            ApiError::Timeout(_) => "Request timed out",
+            ApiError::Connect { .. } => "Cannot reach the Potluck API",
+            ApiError::Tls(_) => "Secure connection failed",
+            ApiError::RateLimited { .. } => "Too many requests",
+            ApiError::Status { status, .. } if *status >= 500 => "Server error",
+            ApiError::Status { .. } => "Request rejected",
+            ApiError::Decode(_) => "Unexpected response",
+            ApiError::Request(_) => "Failed to load articles",
+        }
+    }
+
+    /// What the user can do about it
+    pub fn hint(&self) -> String {
+        match self {
+            ApiError::Timeout(_) => {
+                "The server is slow or unreachable. Check your connection and retry.".to_string()
+            }
+            ApiError::Connect { .. } => {
+                "Check your network connection and that --api-url / POTLUCK_API_URL is correct."
+                    .to_string()
+            }
+            ApiError::Tls(_) => {
+                "The server certificate could not be verified. Check the URL scheme and your system clock."
+                    .to_string()
+            }
+            ApiError::RateLimited {
+                retry_after: Some(delay),
+            } => format!("Wait {}s before retrying.", delay.as_secs().max(1)),
+            ApiError::RateLimited { retry_after: None } => {
+                "Wait a minute before retrying.".to_string()
+            }
+            ApiError::Status { status: 404, .. } => {
+                "The endpoint was not found. Is --api-url pointing at a Potluck server?".to_string()
+            }
+            ApiError::Status { status, .. } if *status >= 500 => {
+                "The server had a problem. Try again in a few minutes.".to_string()
+            }
+            ApiError::Status { .. } => "The server rejected the request.".to_string(),
+            ApiError::Decode(_) => {
+                "The server and ptlk disagree on the response format. Try updating ptlk."
+                    .to_string()
+            }
+            ApiError::Request(_) => "Retry, or check your network connection.".to_string(),
+        }
+    }
+
+    /// Whether the same request may succeed if sent again
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ApiError::Timeout(_)
+            | ApiError::Connect { .. }
+            | ApiError::RateLimited { .. }
+            | ApiError::Request(_) => true,
+            ApiError::Status { status, .. } => matches!(status, 500 | 502 | 503 | 504),
+            ApiError::Tls(_) | ApiError::Decode(_) => false,
+        }
+    }
+
+    fn from_status(status: StatusCode, body: &str, retry_after: Option<Duration>) -> Self {
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return ApiError::RateLimited { retry_after };
+        }
+
+        ApiError::Status {
+            status: status.as_u16(),
+            body: error_message(body),
+        }
+    }
+
+    fn from_reqwest(error: reqwest::Error, url: &str) -> Self {
+        if error.is_timeout() {
+            return ApiError::Timeout(REQUEST_TIMEOUT);
+        }
+
+        let message = root_cause(&error);
+        let lower = message.to_lowercase();
+        if lower.contains("certificate") || lower.contains("tls") || lower.contains("handshake") {
+            return ApiError::Tls(message);
+        }
+        if error.is_connect() {
+            return ApiError::Connect {
+                url: url.to_string(),
+                message,
+            };
+        }
+        if error.is_decode() {
+            return ApiError::Decode(message);
+        }
+
+        ApiError::Request(message)
+    }
+}
+
+/// Innermost error message, e.g. "dns error: failed to lookup address" rather
+/// than reqwest's generic "error sending request"
+fn root_cause(error: &reqwest::Error) -> String {
+    let mut source: &dyn std::error::Error = error;
+    while let Some(next) = source.source() {
+        source = next;
+    }
+    source.to_string()
+}
+
+/// Pull `message` out of the server's JSON error body, falling back to raw text
+fn error_message(body: &str) -> String {
+    #[derive(Deserialize)]
+    struct ErrorBody {
+        message: Option<String>,
+        #[serde(rename = "statusMessage")]
+        status_message: Option<String>,
+    }
+
+    let message = serde_json::from_str::<ErrorBody>(body)
+        .ok()
+        .and_then(|e| e.message.or(e.status_message))
+        .unwrap_or_else(|| body.trim().to_string());
+
+    match message.char_indices().nth(MAX_ERROR_BODY) {
+        Some((end, _)) => format!("{}…", &message[..end]),
+        None => message,
+    }
+}
+
+fn decode<T: DeserializeOwned>(body: &str) -> Result<T, ApiError> {
+    serde_json::from_str(body).map_err(|e| ApiError::Decode(e.to_string()))
+}
 
 /// Article data structure
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Article {
     pub title: String,
     pub url: String,
@@ -16,6 +193,9 @@ pub struct Article {
 
     #[serde(rename = "publishedAt")]
     pub published_at: String,
+    /// Day the article appears in the briefing (YYYY-MM-DD), the day after publication
+    #[serde(rename = "briefingDate", default)]
+    pub briefing_date: String,
 }
 
 fn default_tag() -> String {
@@ -26,21 +206,169 @@ fn default_source() -> String {
     "Unknown".to_string()
 }
 
+/// Page-based pagination metadata returned by `/api/latest`
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Pagination {
+    pub page: u32,
+    pub limit: u32,
+    pub total: u32,
+    #[serde(rename = "totalPages")]
+    pub total_pages: u32,
+}
+
 /// Latest articles response
 #[derive(Debug, Deserialize)]
 pub struct LatestResponse {
     pub data: Vec<Article>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// Timeline articles response (cursor-based)
+#[derive(Debug, Deserialize)]
+pub struct TimelineResponse {
+    pub data: Vec<Article>,
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "hasMore")]
+    pub has_more: bool,
+}
+
+/// Inclusive range of briefing dates accepted by `/api/timeline?startDate=&endDate=`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    /// Build a range from optional bounds, normalizing a missing bound to the
+    /// other one the same way the server does.
+    pub fn new(start: Option<NaiveDate>, end: Option<NaiveDate>) -> Result<Option<Self>> {
+        let (start, end) = match (start, end) {
+            (None, None) => return Ok(None),
+            (Some(start), None) => (start, start),
+            (None, Some(end)) => (end, end),
+            (Some(start), Some(end)) => (start, end),
+        };
+
+        Self::checked(start, end).map(Some)
+    }
+
+    /// Parse `YYYY-MM-DD` or `YYYY-MM-DD..YYYY-MM-DD`
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.trim();
+        let (start, end) = match input.split_once("..") {
+            Some((start, end)) => (parse_date(start)?, parse_date(end)?),
+            None => {
+                let date = parse_date(input)?;
+                (date, date)
+            }
+        };
+
+        Self::checked(start, end)
+    }
+
+    fn checked(start: NaiveDate, end: NaiveDate) -> Result<Self> {
+        // Mirrors the server's 400 "Invalid date range" check
+        if start > end {
+            bail!("Invalid date range: {} is after {}", start, end);
+        }
+
+        Ok(Self { start, end })
+    }
+
+    pub fn label(&self) -> String {
+        if self.start == self.end {
+            self.start.to_string()
+        } else {
+            format!("{}..{}", self.start, self.end)
+        }
+    }
+}
+
+/// Parse a `YYYY-MM-DD` date, the only format the timeline endpoint accepts
+pub fn parse_date(input: &str) -> Result<NaiveDate> {
+    let input = input.trim();
+    match NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        Ok(date) if input.len() == 10 => Ok(date),
+        _ => bail!("Invalid date '{}': expected YYYY-MM-DD", input),
+    }
 }
 
+/// How failed GET requests are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; 0 disables retrying
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff with jitter for the given retry (1-based): a random
+    /// delay between half and all of `base_delay * 2^(retry - 1)`, capped at `max_delay`
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(1 << retry.saturating_sub(1).min(16))
+            .min(self.max_delay);
+        let half = exp / 2;
+        half + half.mul_f64(fastrand::f64())
+    }
+}
+
+/// A retry that is waiting to be sent, for the status line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryNotice {
+    pub attempt: u32,
+    pub max_retries: u32,
+    /// When the retry is sent
+    pub at: Instant,
+    pub reason: String,
+}
+
+impl RetryNotice {
+    /// Time left until the retry, for a countdown
+    pub fn remaining(&self) -> Duration {
+        self.at.saturating_duration_since(Instant::now())
+    }
+}
+
+/// Parse a `Retry-After` header: either delay-seconds or an HTTP-date
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let delay = date.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(delay.to_std().unwrap_or(Duration::ZERO))
+}
+
+#[derive(Clone)]
 pub struct ApiClient {
     client: reqwest::Client,
     pub base_url: String,
+    retry: RetryPolicy,
+    retry_notice: Arc<Mutex<Option<RetryNotice>>>,
 }
 
 impl ApiClient {
     pub fn new(base_url: &str) -> Self {
         let client = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(10))
+            .timeout(REQUEST_TIMEOUT)
             .user_agent("ptlk/0.1.0")
             .build()
             .expect("Failed to create HTTP client");
@@ -48,22 +376,209 @@ impl ApiClient {
         Self {
             client,
             base_url: base_url.trim_end_matches('/').to_string(),
+            retry: RetryPolicy::default(),
+            retry_notice: Arc::new(Mutex::new(None)),
         }
     }
 
-    pub async fn fetch_latest(&self, page: u32, limit: u32) -> Result<LatestResponse> {
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// A clone whose retries don't show up in this client's `retry_notice`,
+    /// for requests the user isn't waiting on
+    pub fn detached(&self) -> Self {
+        Self {
+            retry_notice: Arc::new(Mutex::new(None)),
+            ..self.clone()
+        }
+    }
+
+    /// The retry currently being waited on, if any. Shared by clones of this
+    /// client, except detached ones.
+    pub fn retry_notice(&self) -> Option<RetryNotice> {
+        self.retry_notice
+            .lock()
+            .ok()
+            .and_then(|notice| notice.clone())
+    }
+
+    fn set_retry_notice(&self, notice: Option<RetryNotice>) {
+        if let Ok(mut current) = self.retry_notice.lock() {
+            *current = notice;
+        }
+    }
+
+    pub async fn fetch_latest(&self, page: u32, limit: u32) -> Result<LatestResponse, ApiError> {
         let url = format!("{}/api/latest?page={}&limit={}", self.base_url, page, limit);
 
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await?
-            .error_for_status()?
-            .json::<LatestResponse>()
-            .await?;
+        self.get_json(self.client.get(&url), &url).await
+    }
+
+    /// Fetch articles published strictly before `cursor` (or before now when `None`).
+    pub async fn fetch_timeline(
+        &self,
+        cursor: Option<&str>,
+        limit: u32,
+    ) -> Result<TimelineResponse, ApiError> {
+        let url = format!("{}/api/timeline", self.base_url);
+
+        let mut request = self.client.get(&url).query(&[("limit", limit.to_string())]);
+        if let Some(cursor) = cursor {
+            request = request.query(&[("cursor", cursor)]);
+        }
+
+        self.get_json(request, &url).await
+    }
+
+    /// Fetch every article (up to the server's cap of 1000) briefed within `range`
+    pub async fn fetch_timeline_range(
+        &self,
+        range: DateRange,
+    ) -> Result<TimelineResponse, ApiError> {
+        let url = format!("{}/api/timeline", self.base_url);
+
+        let request = self.client.get(&url).query(&[
+            ("startDate", range.start.to_string()),
+            ("endDate", range.end.to_string()),
+        ]);
+
+        self.get_json(request, &url).await
+    }
+
+    /// Send a GET request and decode its JSON body, retrying transient
+    /// failures according to the client's [`RetryPolicy`]
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+        url: &str,
+    ) -> Result<T, ApiError> {
+        let mut retries = 0;
 
-        Ok(response)
+        loop {
+            let attempt = request
+                .try_clone()
+                .expect("GET requests have no streaming body");
+
+            let (error, retry_after) = match self.send_once(attempt, url).await {
+                Ok(value) => {
+                    self.set_retry_notice(None);
+                    return Ok(value);
+                }
+                Err(failure) => failure,
+            };
+
+            if retries >= self.retry.max_retries || !error.is_retryable() {
+                self.set_retry_notice(None);
+                return Err(error);
+            }
+
+            let delay = match retry_after {
+                Some(delay) if delay > MAX_RETRY_AFTER => {
+                    self.set_retry_notice(None);
+                    return Err(error);
+                }
+                Some(delay) => delay,
+                None => self.retry.backoff(retries + 1),
+            };
+
+            retries += 1;
+            logging::record(
+                Level::Info,
+                "retry",
+                json!({
+                    "url": url,
+                    "attempt": retries,
+                    "delay_ms": delay.as_millis() as u64,
+                    "reason": error.title(),
+                }),
+            );
+            self.set_retry_notice(Some(RetryNotice {
+                attempt: retries,
+                max_retries: self.retry.max_retries,
+                at: Instant::now() + delay,
+                reason: error.title().to_string(),
+            }));
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// One attempt; failures carry the server's `Retry-After` (honored on 429 and 503)
+    async fn send_once<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+        url: &str,
+    ) -> Result<T, (ApiError, Option<Duration>)> {
+        let (client, request) = request.build_split();
+        let request = request.map_err(|e| (ApiError::from_reqwest(e, url), None))?;
+        let full_url = request.url().to_string();
+        let started = Instant::now();
+        let log_failure = |error: &ApiError| {
+            logging::record(
+                Level::Warn,
+                "request_failed",
+                json!({
+                    "url": full_url,
+                    "latency_ms": started.elapsed().as_millis() as u64,
+                    "error": error.to_string(),
+                }),
+            );
+        };
+
+        logging::record(Level::Debug, "request", json!({ "url": full_url }));
+        let response = client.execute(request).await.map_err(|e| {
+            let error = ApiError::from_reqwest(e, url);
+            log_failure(&error);
+            (error, None)
+        })?;
+
+        let status = response.status();
+        let retry_after = match status {
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE => response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_retry_after),
+            _ => None,
+        };
+
+        let body = response.text().await.map_err(|e| {
+            let error = ApiError::from_reqwest(e, url);
+            log_failure(&error);
+            (error, None)
+        })?;
+
+        logging::record(
+            if status.is_success() {
+                Level::Info
+            } else {
+                Level::Warn
+            },
+            "response",
+            json!({
+                "url": full_url,
+                "status": status.as_u16(),
+                "latency_ms": started.elapsed().as_millis() as u64,
+                "bytes": body.len(),
+            }),
+        );
+
+        if !status.is_success() {
+            return Err((
+                ApiError::from_status(status, &body, retry_after),
+                retry_after,
+            ));
+        }
+
+        decode(&body).map_err(|e| {
+            logging::record(
+                Level::Error,
+                "decode_error",
+                json!({ "url": full_url, "error": e.to_string() }),
+            );
+            (e, None)
+        })
     }
 }
 
@@ -79,12 +594,14 @@ mod tests {
             "summary": "Test summary",
             "tag": "AI",
             "source": "TechCrunch",
-            "publishedAt": "2024-01-01T00:00:00Z"
+            "publishedAt": "2024-01-01T00:00:00Z",
+            "briefingDate": "2024-01-02"
         }"#;
 
         let article: Article = serde_json::from_str(json).unwrap();
         assert_eq!(article.title, "Test Article");
         assert_eq!(article.tag, "AI");
+        assert_eq!(article.briefing_date, "2024-01-02");
     }
 
     #[test]
@@ -101,4 +618,240 @@ mod tests {
         assert_eq!(article.source, "Unknown");
         assert_eq!(article.summary, "");
     }
+
+    #[test]
+    fn test_latest_response_pagination() {
+        let json = r#"{
+            "data": [],
+            "pagination": { "page": 2, "limit": 50, "total": 132, "totalPages": 3 }
+        }"#;
+
+        let response: LatestResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            response.pagination,
+            Pagination {
+                page: 2,
+                limit: 50,
+                total: 132,
+                total_pages: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_timeline_response_deserialization() {
+        let json = r#"{
+            "data": [{
+                "title": "Test",
+                "url": "https://example.com",
+                "publishedAt": "2024-01-01T00:00:00+00:00"
+            }],
+            "nextCursor": "2024-01-01T00:00:00+00:00",
+            "hasMore": true
+        }"#;
+
+        let response: TimelineResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(
+            response.next_cursor.as_deref(),
+            Some("2024-01-01T00:00:00+00:00")
+        );
+        assert!(response.has_more);
+
+        let json = r#"{ "data": [], "nextCursor": null, "hasMore": false }"#;
+        let response: TimelineResponse = serde_json::from_str(json).unwrap();
+        assert!(response.next_cursor.is_none());
+        assert!(!response.has_more);
+    }
+
+    #[test]
+    fn test_api_error_from_status() {
+        let error = ApiError::from_status(
+            StatusCode::TOO_MANY_REQUESTS,
+            "",
+            Some(Duration::from_secs(30)),
+        );
+        assert_eq!(
+            error,
+            ApiError::RateLimited {
+                retry_after: Some(Duration::from_secs(30))
+            }
+        );
+        assert!(error.hint().contains("30s"));
+
+        // Nuxt error bodies carry the message in JSON
+        let error = ApiError::from_status(
+            StatusCode::BAD_REQUEST,
+            r#"{"statusCode":400,"message":"Invalid date range"}"#,
+            None,
+        );
+        assert_eq!(
+            error,
+            ApiError::Status {
+                status: 400,
+                body: "Invalid date range".to_string()
+            }
+        );
+
+        let error = ApiError::from_status(StatusCode::BAD_GATEWAY, &"x".repeat(500), None);
+        match &error {
+            ApiError::Status { status, body } => {
+                assert_eq!(*status, 502);
+                assert_eq!(body.chars().count(), MAX_ERROR_BODY + 1);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert_eq!(error.title(), "Server error");
+    }
+
+    #[test]
+    fn test_decode_schema_mismatch() {
+        let error = decode::<LatestResponse>(r#"{"articles": []}"#).unwrap_err();
+        assert!(matches!(error, ApiError::Decode(ref msg) if msg.contains("data")));
+    }
+
+    #[test]
+    fn test_retry_backoff_bounds() {
+        let policy = RetryPolicy::default();
+
+        for retry in 1..=3 {
+            let full = policy.base_delay * (1 << (retry - 1));
+            let delay = policy.backoff(retry);
+            assert!(
+                delay >= full / 2 && delay <= full,
+                "{delay:?} for retry {retry}"
+            );
+        }
+
+        // Capped no matter how many retries
+        assert!(policy.backoff(30) <= policy.max_delay);
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
+    #[test]
+    fn test_retryable_errors() {
+        assert!(ApiError::Timeout(REQUEST_TIMEOUT).is_retryable());
+        assert!(ApiError::Status {
+            status: 503,
+            body: String::new()
+        }
+        .is_retryable());
+        assert!(!ApiError::Status {
+            status: 400,
+            body: String::new()
+        }
+        .is_retryable());
+        assert!(!ApiError::Decode(String::new()).is_retryable());
+    }
+
+    /// Serve each canned response to one connection, in order
+    async fn serve(responses: Vec<&'static str>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = [0; 1024];
+                let _ = socket.read(&mut buf).await;
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.shutdown().await.unwrap();
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_retries_503_with_retry_after() {
+        let url = serve(vec![
+            "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 11\r\nConnection: close\r\n\r\n{\"data\":[]}",
+        ])
+        .await;
+
+        let client = ApiClient::new(&url);
+        let response = client.fetch_latest(1, 10).await.unwrap();
+        assert!(response.data.is_empty());
+        assert!(client.retry_notice().is_none());
+    }
+
+    #[test]
+    fn test_detached_client_has_its_own_retry_notice() {
+        let client = ApiClient::new("http://localhost:3000");
+        let background = client.detached();
+        background.set_retry_notice(Some(RetryNotice {
+            attempt: 1,
+            max_retries: 3,
+            at: Instant::now() + Duration::from_secs(5),
+            reason: "Server error".to_string(),
+        }));
+
+        assert!(client.retry_notice().is_none());
+        let notice = background.retry_notice().unwrap();
+        assert!(notice.remaining() <= Duration::from_secs(5));
+        assert!(notice.remaining() > Duration::from_secs(4));
+
+        // Clones share the notice
+        client.clone().set_retry_notice(background.retry_notice());
+        assert!(client.retry_notice().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_when_disabled() {
+        let url = serve(vec![
+            "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ])
+        .await;
+
+        let client = ApiClient::new(&url).with_retry_policy(RetryPolicy {
+            max_retries: 0,
+            ..RetryPolicy::default()
+        });
+        let error = client.fetch_latest(1, 10).await.unwrap_err();
+        assert_eq!(
+            error,
+            ApiError::Status {
+                status: 503,
+                body: String::new()
+            }
+        );
+    }
+
+    #[test]
+    fn test_date_range_parse() {
+        let range = DateRange::parse("2026-10-01..2026-10-05").unwrap();
+        assert_eq!(range.start.to_string(), "2026-10-01");
+        assert_eq!(range.end.to_string(), "2026-10-05");
+        assert_eq!(range.label(), "2026-10-01..2026-10-05");
+
+        let single = DateRange::parse(" 2026-10-16 ").unwrap();
+        assert_eq!(single.start, single.end);
+        assert_eq!(single.label(), "2026-10-16");
+
+        // Same rule as the server: start after end is rejected
+        assert!(DateRange::parse("2026-10-05..2026-10-01").is_err());
+        assert!(DateRange::parse("2026-1-5").is_err());
+        assert!(DateRange::parse("yesterday").is_err());
+    }
+
+    #[test]
+    fn test_date_range_normalizes_missing_bound() {
+        let date = parse_date("2026-10-16").unwrap();
+
+        let range = DateRange::new(None, Some(date)).unwrap().unwrap();
+        assert_eq!(range.start, date);
+        assert_eq!(range.end, date);
+
+        assert!(DateRange::new(None, None).unwrap().is_none());
+    }
 }
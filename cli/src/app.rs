@@ -1,25 +1,242 @@
-use crate::api::{ApiClient, Article};
-use anyhow::Result;
+use crate::api::{ApiClient, ApiError, Article, DateRange, Pagination};
+use crate::bookmarks::Bookmarks;
+use crate::cache::ArticleCache;
+use crate::config::Filters;
+use crate::facets::FacetPicker;
+use crate::fetch::{FetchJob, FetchKind, FetchRequest, FetchResult, Page};
+use crate::keymap::Keymap;
+use crate::read_state::ReadState;
+use crate::search;
+use crate::theme::Theme;
 use ratatui::widgets::ListState;
 use std::collections::HashSet;
+use std::mem;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum LoadingState {
     Loading,
     Loaded,
-    Error(String),
+    Error(ApiError),
 }
 
+/// Which feed the article list is showing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewMode {
+    /// Today's and yesterday's articles from `/api/latest`
+    Latest,
+    /// Every article, newest first, paged by cursor from `/api/timeline`
+    Timeline,
+    /// Articles briefed within a fixed date range from `/api/timeline`
+    Range(DateRange),
+    /// Bookmarked articles, from the local store
+    Saved,
+    /// Articles matching the last submitted search, from every article
+    /// loaded, cached or saved
+    Search,
+}
+
+impl ViewMode {
+    pub fn label(&self) -> String {
+        match self {
+            ViewMode::Latest => "Latest".to_string(),
+            ViewMode::Timeline => "Timeline".to_string(),
+            ViewMode::Range(range) => range.label(),
+            ViewMode::Saved => "Saved".to_string(),
+            ViewMode::Search => "Search".to_string(),
+        }
+    }
+}
+
+/// The tabs in the tab bar, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tab {
+    Latest,
+    Timeline,
+    Saved,
+    Search,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 4] = [Tab::Latest, Tab::Timeline, Tab::Saved, Tab::Search];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Tab::Latest => "Latest",
+            Tab::Timeline => "Timeline",
+            Tab::Saved => "Saved",
+            Tab::Search => "Search",
+        }
+    }
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// What a tab shows when first opened
+    fn mode(self) -> ViewMode {
+        match self {
+            Tab::Latest => ViewMode::Latest,
+            Tab::Timeline => ViewMode::Timeline,
+            Tab::Saved => ViewMode::Saved,
+            Tab::Search => ViewMode::Search,
+        }
+    }
+}
+
+/// A tab's list while another tab is active
+struct TabState {
+    mode: ViewMode,
+    loaded: Vec<Article>,
+    articles: Vec<Article>,
+    list_state: ListState,
+    expanded: HashSet<String>,
+    loading_state: LoadingState,
+    filters: Filters,
+    page: u32,
+    total: Option<u32>,
+    next_cursor: Option<String>,
+    has_more: bool,
+    stale_reason: Option<String>,
+    new_articles: usize,
+    /// A fetch was still running when the tab was left; reload on return
+    interrupted: bool,
+}
+
+/// Order of the article list; sections follow the sort key
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Server order: newest first, in briefing-day sections
+    #[default]
+    Newest,
+    Oldest,
+    Source,
+    Tag,
+    /// Unread articles first, each group newest first
+    UnreadFirst,
+}
+
+impl SortOrder {
+    pub fn label(self) -> &'static str {
+        match self {
+            SortOrder::Newest => "newest",
+            SortOrder::Oldest => "oldest",
+            SortOrder::Source => "source",
+            SortOrder::Tag => "tag",
+            SortOrder::UnreadFirst => "unread first",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            SortOrder::Newest => SortOrder::Oldest,
+            SortOrder::Oldest => SortOrder::Source,
+            SortOrder::Source => SortOrder::Tag,
+            SortOrder::Tag => SortOrder::UnreadFirst,
+            SortOrder::UnreadFirst => SortOrder::Newest,
+        }
+    }
+}
+
+/// Single-line text input shown over the article list
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Prompt {
+    pub input: String,
+    pub error: Option<String>,
+}
+
+/// Start loading the next page when the selection is this close to the end
+const LOAD_MORE_THRESHOLD: usize = 5;
+
+/// An article left expanded and selected this long counts as read
+const READ_DWELL: Duration = Duration::from_secs(5);
+
+/// Narrowest terminal that gets the list and detail pane side by side
+const SPLIT_MIN_WIDTH: u16 = 120;
+
+/// Share of the width the list gets in the split layout, in percent
+const DEFAULT_SPLIT_PERCENT: u16 = 45;
+const MIN_SPLIT_PERCENT: u16 = 25;
+const MAX_SPLIT_PERCENT: u16 = 75;
+const SPLIT_STEP: u16 = 5;
+
 pub struct App {
+    /// Articles shown in the list: `loaded` narrowed by `filters`, in `sort` order
     pub articles: Vec<Article>,
+    /// Every article fetched for the current view, before filtering
+    loaded: Vec<Article>,
     pub list_state: ListState,
-    pub expanded: HashSet<usize>,
+    /// URLs of expanded articles; URLs are unique, unlike list positions
+    pub expanded: HashSet<String>,
     pub loading_state: LoadingState,
     pub client: ApiClient,
     limit: u32,
     pub should_quit: bool,
     pub last_error: Option<String>,
     pub needs_refresh: bool,
+    /// The active tab; the fields below up to `filters` hold its list
+    pub tab: Tab,
+    /// Every other tab's list, by [`Tab::index`], once it has been opened
+    parked: [Option<TabState>; 4],
+    pub mode: ViewMode,
+    page: u32,
+    pub total: Option<u32>,
+    next_cursor: Option<String>,
+    pub has_more: bool,
+    pub loading_more: bool,
+    pub date_prompt: Option<Prompt>,
+    pub cache: Option<ArticleCache>,
+    /// Skip the network and serve everything from `cache`
+    pub offline: bool,
+    /// Set while the list shows cached articles instead of a fresh fetch
+    pub stale_reason: Option<String>,
+    generation: u64,
+    jobs: Vec<FetchJob>,
+    reloading: bool,
+    /// Animation frame counter for the loading spinner
+    pub tick: usize,
+    polling: bool,
+    /// Articles found by the last background poll that aren't in the list yet
+    pub new_articles: usize,
+    pub keymap: Keymap,
+    pub theme: Theme,
+    /// Articles not matching these are hidden; kept across refreshes
+    pub filters: Filters,
+    /// Filters a tab starts with, from the config file
+    pub default_filters: Filters,
+    /// Open while picking tags and sources to filter by
+    pub facet_picker: Option<FacetPicker>,
+    pub sort: SortOrder,
+    /// Articles opened, read in place, or marked read by hand
+    pub read: ReadState,
+    pub bookmarks: Bookmarks,
+    /// The expanded, selected article and when it was expanded or selected,
+    /// until it's been on screen for `READ_DWELL`
+    dwell: Option<(String, Instant)>,
+    /// Show the selected article in a detail pane beside the list when the
+    /// terminal is wide enough
+    pub split: bool,
+    /// Whether the detail pane is on screen: `split` on a wide enough terminal
+    pub split_active: bool,
+    terminal_width: u16,
+    /// Share of the width the list gets in the split layout, in percent
+    pub split_percent: u16,
+    /// Lines the detail pane is scrolled down; reset when the selection changes
+    pub detail_scroll: u16,
+    detail_url: Option<String>,
+    /// The article scrolled in the detail pane, which counts as reading it
+    /// like expanding it does; cleared when the selection changes
+    detail_read: Option<String>,
+    /// Show the debug log pane under the list
+    pub show_log: bool,
+    /// Open while typing a search; its input is mirrored into `search`
+    pub search_prompt: Option<Prompt>,
+    /// Active search query; matches are highlighted until it's cleared
+    pub search: String,
+    /// Last submitted query, listed by the Search tab
+    pub search_results_query: String,
+    /// Selection before the search prompt opened, restored on cancel
+    search_origin: Option<usize>,
 }
 
 impl App {
@@ -29,6 +246,7 @@ impl App {
 
         Self {
             articles: Vec::new(),
+            loaded: Vec::new(),
             list_state,
             expanded: HashSet::new(),
             loading_state: LoadingState::Loading,
@@ -37,29 +255,596 @@ impl App {
             should_quit: false,
             last_error: None,
             needs_refresh: false,
+            tab: Tab::Latest,
+            parked: Default::default(),
+            mode: ViewMode::Latest,
+            page: 0,
+            total: None,
+            next_cursor: None,
+            has_more: false,
+            loading_more: false,
+            date_prompt: None,
+            cache: None,
+            offline: false,
+            stale_reason: None,
+            generation: 0,
+            jobs: Vec::new(),
+            reloading: false,
+            tick: 0,
+            polling: false,
+            new_articles: 0,
+            keymap: Keymap::default(),
+            theme: Theme::default(),
+            filters: Filters::default(),
+            default_filters: Filters::default(),
+            facet_picker: None,
+            sort: SortOrder::default(),
+            read: ReadState::default(),
+            bookmarks: Bookmarks::default(),
+            dwell: None,
+            split: true,
+            split_active: false,
+            terminal_width: 0,
+            split_percent: DEFAULT_SPLIT_PERCENT,
+            detail_scroll: 0,
+            detail_url: None,
+            detail_read: None,
+            show_log: false,
+            search_prompt: None,
+            search: String::new(),
+            search_results_query: String::new(),
+            search_origin: None,
+        }
+    }
+
+    /// Show cached articles for the current mode. Returns whether any were found.
+    pub fn load_cached(&mut self) -> bool {
+        let articles = self.cached_articles();
+        if articles.is_empty() {
+            return false;
+        }
+
+        self.replace_articles(articles);
+        self.total = None;
+        self.has_more = false;
+        self.loading_state = LoadingState::Loaded;
+        self.stale_reason = Some("Showing cached articles".to_string());
+        true
+    }
+
+    fn cached_articles(&self) -> Vec<Article> {
+        let Some(cache) = &self.cache else {
+            return Vec::new();
+        };
+
+        let articles = cache.articles();
+        match self.mode {
+            ViewMode::Latest => articles.into_iter().take(self.limit as usize).collect(),
+            ViewMode::Timeline => articles,
+            ViewMode::Saved | ViewMode::Search => Vec::new(),
+            ViewMode::Range(range) => {
+                let (start, end) = (range.start.to_string(), range.end.to_string());
+                articles
+                    .into_iter()
+                    .filter(|a| a.briefing_date >= start && a.briefing_date <= end)
+                    .collect()
+            }
+        }
+    }
+
+    fn save_to_cache(&mut self, articles: &[Article]) {
+        if let Some(cache) = self.cache.as_mut() {
+            cache.insert(articles);
+            // The cache is best-effort; a failed write only costs the next offline start
+            let _ = cache.save();
+        }
+    }
+
+    /// Reload the current mode from scratch. Offline mode is served from the
+    /// cache immediately; otherwise a [`FetchJob`] is queued for the event loop.
+    pub fn load_initial(&mut self) {
+        // Results of anything still in flight belong to the previous list
+        self.generation += 1;
+        self.loading_more = false;
+        self.polling = false;
+
+        let request = match self.mode {
+            ViewMode::Latest => FetchRequest::Latest {
+                page: 1,
+                limit: self.limit,
+            },
+            ViewMode::Timeline => FetchRequest::Timeline {
+                cursor: None,
+                limit: self.limit,
+            },
+            ViewMode::Range(range) => FetchRequest::Range(range),
+            ViewMode::Saved => {
+                self.load_local(self.bookmarks.articles());
+                return;
+            }
+            ViewMode::Search => {
+                self.load_local(self.search_results());
+                return;
+            }
+        };
+
+        if self.offline {
+            if !self.load_cached() {
+                self.loaded.clear();
+                self.articles.clear();
+                self.loading_state = LoadingState::Loaded;
+            }
+            self.stale_reason = Some("Offline".to_string());
+            return;
+        }
+
+        // Keep cached or previously loaded articles on screen while fetching
+        if self.articles.is_empty() {
+            self.loading_state = LoadingState::Loading;
         }
+        self.reloading = true;
+        self.queue(FetchKind::Reload, request);
     }
 
-    pub async fn load_initial(&mut self) -> Result<()> {
-        self.loading_state = LoadingState::Loading;
-        match self.client.fetch_latest(1, self.limit).await {
-            Ok(response) => {
-                self.articles = response.data;
+    /// The Saved and Search views never touch the network, offline or not
+    fn load_local(&mut self, articles: Vec<Article>) {
+        self.replace_articles(articles);
+        self.total = None;
+        self.has_more = false;
+        self.loading_state = LoadingState::Loaded;
+        self.stale_reason = None;
+    }
+
+    fn queue(&mut self, kind: FetchKind, request: FetchRequest) {
+        self.jobs.push(FetchJob {
+            generation: self.generation,
+            kind,
+            request,
+        });
+    }
+
+    /// Current reload generation; fetch results from older ones are dropped
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Fetches queued since the last call, for the event loop to spawn
+    pub fn take_fetch_jobs(&mut self) -> Vec<FetchJob> {
+        std::mem::take(&mut self.jobs)
+    }
+
+    /// Whether a reload or next-page fetch is in flight
+    pub fn is_fetching(&self) -> bool {
+        self.reloading || self.loading_more
+    }
+
+    /// Whether `on_tick` has work to do: animating a fetch, counting down to
+    /// a retry or timing how long an article has been read
+    pub fn needs_tick(&self) -> bool {
+        self.is_fetching()
+            || self.client.retry_notice().is_some()
+            || self.dwell_candidate().is_some()
+    }
+
+    /// Apply a finished fetch. Results from before the last reload are ignored.
+    pub fn handle_fetch_result(&mut self, result: FetchResult) {
+        if result.generation != self.generation {
+            return;
+        }
+
+        match result.kind {
+            FetchKind::Reload => {
+                self.reloading = false;
+                self.apply_reload(result.result);
+            }
+            FetchKind::More => {
+                self.loading_more = false;
+                self.apply_more(result.result);
+            }
+            FetchKind::Poll => {
+                self.polling = false;
+                self.apply_poll(result.result);
+            }
+        }
+    }
+
+    fn apply_reload(&mut self, result: Result<Page, ApiError>) {
+        match result {
+            Ok(page) => {
+                self.apply_page_position(&page);
+                if let ViewMode::Range(_) = self.mode {
+                    self.total = Some(page.articles.len() as u32);
+                }
+
+                self.save_to_cache(&page.articles);
+                self.replace_articles(page.articles);
                 self.loading_state = LoadingState::Loaded;
-                if !self.articles.is_empty() {
-                    self.list_state.select(Some(0));
+                self.stale_reason = None;
+                self.new_articles = 0;
+            }
+            Err(e) => {
+                if self.articles.is_empty() && !self.load_cached() {
+                    self.loading_state = LoadingState::Error(e);
+                } else {
+                    // Fall back to what is already on screen and flag it as stale
+                    self.loading_state = LoadingState::Loaded;
+                    self.stale_reason = Some(e.title().to_string());
                 }
             }
+        }
+    }
+
+    fn apply_more(&mut self, result: Result<Page, ApiError>) {
+        match result {
+            Ok(page) => {
+                self.apply_page_position(&page);
+                self.save_to_cache(&page.articles);
+                self.append_articles(page.articles);
+            }
             Err(e) => {
-                self.loading_state = LoadingState::Error(e.to_string());
+                // Stop paging until the next refresh instead of retrying on every tick
+                self.has_more = false;
+                self.last_error = Some(format!("Failed to load more articles: {}", e));
+            }
+        }
+    }
+
+    fn apply_poll(&mut self, result: Result<Page, ApiError>) {
+        // A failed poll is retried on the next interval; the list is still valid
+        let Ok(page) = result else {
+            return;
+        };
+
+        self.save_to_cache(&page.articles);
+
+        let listed: HashSet<&str> = self.loaded.iter().map(|a| a.url.as_str()).collect();
+        let newest = self
+            .loaded
+            .iter()
+            .map(|a| a.published_at.as_str())
+            .max()
+            .unwrap_or_default();
+        self.new_articles = page
+            .articles
+            .iter()
+            .filter(|a| !listed.contains(a.url.as_str()) && a.published_at.as_str() > newest)
+            .filter(|a| self.filters.matches(a))
+            .count();
+    }
+
+    /// Check `/api/latest` for articles newer than the list, without touching it.
+    /// Called by the event loop on the `--refresh-interval` timer.
+    pub fn poll_for_new(&mut self) {
+        if self.offline
+            || self.polling
+            || self.reloading
+            || matches!(
+                self.mode,
+                ViewMode::Range(_) | ViewMode::Saved | ViewMode::Search
+            )
+            || self.loading_state != LoadingState::Loaded
+        {
+            return;
+        }
+
+        self.polling = true;
+        self.queue(
+            FetchKind::Poll,
+            FetchRequest::Latest {
+                page: 1,
+                limit: self.limit,
+            },
+        );
+    }
+
+    fn apply_page_position(&mut self, page: &Page) {
+        match page.pagination {
+            Some(pagination) => self.apply_pagination(pagination),
+            None => {
+                self.page = 0;
+                self.total = None;
+                self.has_more = page.has_more;
+            }
+        }
+        self.next_cursor = page.next_cursor.clone();
+    }
+
+    fn apply_pagination(&mut self, pagination: Pagination) {
+        self.page = pagination.page;
+        self.total = Some(pagination.total);
+        self.has_more = pagination.page < pagination.total_pages;
+    }
+
+    /// Whether the selection is close enough to the end to fetch the next page
+    pub fn should_load_more(&self) -> bool {
+        if !self.has_more
+            || self.loading_more
+            || self.reloading
+            || self.loading_state != LoadingState::Loaded
+        {
+            return false;
+        }
+
+        // The next page continues from the oldest loaded article, which other
+        // sort orders move away from the end of the list
+        let selected = self.list_state.selected().unwrap_or(0);
+        match self.oldest_loaded_index() {
+            Some(oldest) => selected.abs_diff(oldest) < LOAD_MORE_THRESHOLD,
+            None => true,
+        }
+    }
+
+    /// Position in the list of the last article in server order that the
+    /// filters let through
+    fn oldest_loaded_index(&self) -> Option<usize> {
+        self.loaded
+            .iter()
+            .rev()
+            .find_map(|a| self.articles.iter().position(|b| b.url == a.url))
+    }
+
+    /// Queue the next page: `page + 1` for Latest, `next_cursor` for Timeline
+    pub fn load_more(&mut self) {
+        let request = match (self.mode, self.next_cursor.clone()) {
+            (ViewMode::Latest, _) => FetchRequest::Latest {
+                page: self.page + 1,
+                limit: self.limit,
+            },
+            (ViewMode::Timeline, Some(cursor)) => FetchRequest::Timeline {
+                cursor: Some(cursor),
+                limit: self.limit,
+            },
+            (ViewMode::Timeline, None)
+            | (ViewMode::Range(_) | ViewMode::Saved | ViewMode::Search, _) => {
+                self.has_more = false;
+                return;
+            }
+        };
+
+        self.loading_more = true;
+        self.queue(FetchKind::More, request);
+    }
+
+    /// Advance animations; called by the event loop on every idle tick
+    pub fn on_tick(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+        self.check_dwell(Instant::now());
+    }
+
+    /// Swap in a new list, keeping the selected article selected and dropping
+    /// expansion state for articles that are gone
+    fn replace_articles(&mut self, articles: Vec<Article>) {
+        self.loaded = articles;
+
+        let listed: HashSet<&str> = self.loaded.iter().map(|a| a.url.as_str()).collect();
+        self.expanded.retain(|url| listed.contains(url.as_str()));
+
+        self.apply_filters();
+    }
+
+    /// Append a page, skipping articles already loaded. Offset pages shift when
+    /// new articles are ingested between requests.
+    fn append_articles(&mut self, articles: Vec<Article>) {
+        let seen: HashSet<String> = self.loaded.iter().map(|a| a.url.clone()).collect();
+        let new: Vec<Article> = articles
+            .into_iter()
+            .filter(|a| !seen.contains(&a.url))
+            .collect();
+        if self.sort == SortOrder::Newest {
+            self.articles
+                .extend(new.iter().filter(|a| self.filters.matches(a)).cloned());
+            self.loaded.extend(new);
+        } else {
+            self.loaded.extend(new);
+            self.apply_filters();
+        }
+    }
+
+    /// Rebuild the list from `loaded` after `filters` or `sort` changed,
+    /// keeping the selected article selected if it's still shown
+    fn apply_filters(&mut self) {
+        let selected_url = self.selected_article().map(|a| a.url.clone());
+        self.articles = self
+            .loaded
+            .iter()
+            .filter(|a| self.filters.matches(a))
+            .cloned()
+            .collect();
+
+        // Stable sorts, so ties stay in server (newest first) order
+        match self.sort {
+            SortOrder::Newest => {}
+            SortOrder::Oldest => self
+                .articles
+                .sort_by(|a, b| a.published_at.cmp(&b.published_at)),
+            SortOrder::Source => self
+                .articles
+                .sort_by_cached_key(|a| a.source.to_lowercase()),
+            SortOrder::Tag => self.articles.sort_by_cached_key(|a| a.tag.to_lowercase()),
+            SortOrder::UnreadFirst => self.articles.sort_by_key(|a| self.read.is_read(&a.url)),
+        }
+
+        let index = selected_url
+            .and_then(|url| self.articles.iter().position(|a| a.url == url))
+            .unwrap_or(0);
+        self.list_state.select(Some(index));
+    }
+
+    /// "N of total" when the server reports a total, otherwise just the loaded count.
+    /// The server's total counts articles the filters hide, so it's dropped then.
+    pub fn count_label(&self) -> String {
+        let total = self.total.filter(|_| self.filters.is_empty());
+        match total {
+            Some(total) => format!("{} of {}", self.articles.len(), total),
+            None if self.has_more => format!("{}+", self.articles.len()),
+            None => self.articles.len().to_string(),
+        }
+    }
+
+    /// Leave a date range for the tab's own feed, otherwise switch between
+    /// the Latest and Timeline tabs
+    pub fn toggle_mode(&mut self) {
+        if let ViewMode::Range(_) = self.mode {
+            self.set_mode(self.tab.mode());
+            return;
+        }
+        let tab = match self.tab {
+            Tab::Latest => Tab::Timeline,
+            Tab::Timeline | Tab::Saved | Tab::Search => Tab::Latest,
+        };
+        self.switch_tab(tab);
+    }
+
+    /// Switch to the Saved tab, or back to Latest from it
+    pub fn toggle_saved(&mut self) {
+        let tab = match self.tab {
+            Tab::Saved => Tab::Latest,
+            _ => Tab::Saved,
+        };
+        self.switch_tab(tab);
+    }
+
+    pub fn next_tab(&mut self) {
+        self.switch_tab(Tab::ALL[(self.tab.index() + 1) % Tab::ALL.len()]);
+    }
+
+    pub fn previous_tab(&mut self) {
+        self.switch_tab(Tab::ALL[(self.tab.index() + Tab::ALL.len() - 1) % Tab::ALL.len()]);
+    }
+
+    /// Park the active tab's list and bring back `tab`'s as it was left,
+    /// scroll position included. A tab opened for the first time is loaded.
+    pub fn switch_tab(&mut self, tab: Tab) {
+        if tab == self.tab {
+            return;
+        }
+
+        let state = self.park_tab();
+        self.parked[self.tab.index()] = Some(state);
+        self.tab = tab;
+
+        // Fetches still in flight belong to the tab that was left
+        self.generation += 1;
+        self.reloading = false;
+        self.loading_more = false;
+        self.polling = false;
+
+        match self.parked[tab.index()].take() {
+            Some(state) => {
+                let interrupted = state.interrupted;
+                self.restore_tab(state);
+                // Saved and Search are cheap to rebuild and may be out of date
+                if interrupted || matches!(tab, Tab::Saved | Tab::Search) {
+                    self.mark_for_refresh();
+                }
+            }
+            None => {
+                self.filters = self.default_filters.clone();
+                self.set_mode(tab.mode());
             }
         }
-        Ok(())
     }
 
-    pub async fn refresh(&mut self) -> Result<()> {
+    fn park_tab(&mut self) -> TabState {
+        TabState {
+            mode: self.mode,
+            loaded: mem::take(&mut self.loaded),
+            articles: mem::take(&mut self.articles),
+            list_state: mem::take(&mut self.list_state),
+            expanded: mem::take(&mut self.expanded),
+            loading_state: mem::replace(&mut self.loading_state, LoadingState::Loading),
+            filters: mem::take(&mut self.filters),
+            page: self.page,
+            total: self.total.take(),
+            next_cursor: self.next_cursor.take(),
+            has_more: self.has_more,
+            stale_reason: self.stale_reason.take(),
+            new_articles: self.new_articles,
+            interrupted: self.reloading || self.loading_more,
+        }
+    }
+
+    fn restore_tab(&mut self, state: TabState) {
+        self.mode = state.mode;
+        self.loaded = state.loaded;
+        self.articles = state.articles;
+        self.list_state = state.list_state;
+        self.expanded = state.expanded;
+        self.loading_state = state.loading_state;
+        self.filters = state.filters;
+        self.page = state.page;
+        self.total = state.total;
+        self.next_cursor = state.next_cursor;
+        self.has_more = state.has_more;
+        self.stale_reason = state.stale_reason;
+        self.new_articles = state.new_articles;
+    }
+
+    /// Articles matching `search_results_query` from every tab, the cache and
+    /// the bookmarks, newest first
+    fn search_results(&self) -> Vec<Article> {
+        if self.search_results_query.is_empty() {
+            return Vec::new();
+        }
+
+        let parked = self.parked.iter().flatten().map(|tab| &tab.loaded);
+        let cached = self.cache.as_ref().map(|cache| cache.articles());
+        let saved = self.bookmarks.articles();
+        let mut seen = HashSet::new();
+        let mut results: Vec<Article> = parked
+            .chain(Some(&self.loaded).filter(|_| self.tab != Tab::Search))
+            .flatten()
+            .chain(cached.iter().flatten())
+            .chain(&saved)
+            .filter(|a| search::article_matches(a, &self.search_results_query))
+            .filter(|a| seen.insert(a.url.clone()))
+            .cloned()
+            .collect();
+        results.sort_by(|a, b| b.published_at.cmp(&a.published_at));
+        results
+    }
+
+    fn set_mode(&mut self, mode: ViewMode) {
+        self.mode = mode;
+        self.loaded.clear();
+        self.articles.clear();
         self.expanded.clear();
-        self.load_initial().await
+        self.list_state.select(Some(0));
+        self.mark_for_refresh();
+    }
+
+    /// Open the "go to date" prompt
+    pub fn open_date_prompt(&mut self) {
+        self.date_prompt = Some(Prompt::default());
+    }
+
+    pub fn close_date_prompt(&mut self) {
+        self.date_prompt = None;
+    }
+
+    /// Validate the prompt input and reload the list for that date range.
+    /// Invalid input keeps the prompt open with an error message.
+    pub fn submit_date_prompt(&mut self) {
+        let Some(prompt) = self.date_prompt.as_mut() else {
+            return;
+        };
+
+        match DateRange::parse(&prompt.input) {
+            Ok(range) => {
+                self.date_prompt = None;
+                if matches!(self.tab, Tab::Saved | Tab::Search) {
+                    self.switch_tab(Tab::Timeline);
+                }
+                self.set_mode(ViewMode::Range(range));
+            }
+            Err(e) => {
+                prompt.error = Some(e.to_string());
+            }
+        }
+    }
+
+    pub fn refresh(&mut self) {
+        self.load_initial();
     }
 
     pub fn next(&mut self) {
@@ -72,7 +857,12 @@ impl App {
         let i = match self.list_state.selected() {
             Some(i) => {
                 if i >= total_items - 1 {
-                    0
+                    // More timeline pages are on the way; don't wrap past them
+                    if self.has_more {
+                        i
+                    } else {
+                        0
+                    }
                 } else {
                     i + 1
                 }
@@ -114,6 +904,63 @@ impl App {
         }
     }
 
+    /// Whether the article at `index` opens a new briefing-date section
+    pub fn is_section_start(&self, index: usize) -> bool {
+        match (self.articles.get(index), index.checked_sub(1)) {
+            (Some(_), None) => true,
+            (Some(article), Some(prev)) => {
+                self.section_title(&self.articles[prev]) != self.section_title(article)
+            }
+            (None, _) => false,
+        }
+    }
+
+    /// Header of the section `article` falls in: its briefing day, or the
+    /// sort key when sorting by something other than time. Sources and tags
+    /// are case-folded, as they are for sorting.
+    pub fn section_title(&self, article: &Article) -> String {
+        match self.sort {
+            SortOrder::Newest | SortOrder::Oldest if article.briefing_date.is_empty() => {
+                "Undated".to_string()
+            }
+            SortOrder::Newest | SortOrder::Oldest => {
+                format!("{} briefing", article.briefing_date)
+            }
+            SortOrder::Source => article.source.to_lowercase(),
+            SortOrder::Tag => article.tag.to_lowercase(),
+            SortOrder::UnreadFirst if self.read.is_read(&article.url) => "Read".to_string(),
+            SortOrder::UnreadFirst => "Unread".to_string(),
+        }
+    }
+
+    /// Switch to the next sort order, keeping the selected article selected
+    pub fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.apply_filters();
+    }
+
+    /// Jump to the first article of the next section
+    pub fn next_section(&mut self) {
+        let current = self.list_state.selected().unwrap_or(0);
+        if let Some(i) = (current + 1..self.articles.len()).find(|&i| self.is_section_start(i)) {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    /// Jump to the start of the current section, or the previous one when
+    /// already there
+    pub fn previous_section(&mut self) {
+        let current = self.list_state.selected().unwrap_or(0);
+        let from = if self.is_section_start(current) {
+            current.saturating_sub(1)
+        } else {
+            current
+        };
+        if let Some(i) = (0..=from).rev().find(|&i| self.is_section_start(i)) {
+            self.list_state.select(Some(i));
+        }
+    }
+
     pub fn page_down(&mut self) {
         if self.articles.is_empty() {
             return;
@@ -132,16 +979,23 @@ impl App {
     }
 
     pub fn toggle_expand(&mut self) {
-        if let Some(i) = self.list_state.selected() {
-            if self.expanded.contains(&i) {
-                self.expanded.remove(&i);
-            } else {
-                self.expanded.insert(i);
+        if let Some(url) = self.selected_article().map(|a| a.url.clone()) {
+            if !self.expanded.remove(&url) {
+                self.expanded.insert(url);
             }
         }
     }
 
+    pub fn is_expanded(&self, article: &Article) -> bool {
+        self.expanded.contains(&article.url)
+    }
+
     #[allow(dead_code)]
+    pub fn is_current_expanded(&self) -> bool {
+        self.selected_article()
+            .is_some_and(|article| self.is_expanded(article))
+    }
+
     pub fn selected_article(&self) -> Option<&Article> {
         self.list_state
             .selected()
@@ -153,8 +1007,101 @@ impl App {
             if let Some(article) = self.articles.get(i) {
                 if let Err(e) = open::that(&article.url) {
                     self.last_error = Some(format!("Failed to open browser: {}", e));
+                    return;
+                }
+                let url = article.url.clone();
+                self.mark_read([url.as_str()]);
+            }
+        }
+    }
+
+    pub fn is_read(&self, article: &Article) -> bool {
+        self.read.is_read(&article.url)
+    }
+
+    /// Unread articles in the list
+    pub fn unread_count(&self) -> usize {
+        self.articles.iter().filter(|a| !self.is_read(a)).count()
+    }
+
+    fn mark_read<'a>(&mut self, urls: impl IntoIterator<Item = &'a str>) {
+        if self.read.mark_read(urls) {
+            self.save_read_state();
+        }
+    }
+
+    fn save_read_state(&mut self) {
+        if let Err(e) = self.read.save() {
+            self.last_error = Some(format!("Failed to save read state: {:#}", e));
+        }
+    }
+
+    /// Flip the selected article between read and unread
+    pub fn toggle_read(&mut self) {
+        let Some(url) = self.selected_article().map(|a| a.url.clone()) else {
+            return;
+        };
+        if self.read.mark_unread(&url) {
+            self.save_read_state();
+        } else {
+            self.mark_read([url.as_str()]);
+        }
+    }
+
+    pub fn is_bookmarked(&self, article: &Article) -> bool {
+        self.bookmarks.contains(&article.url)
+    }
+
+    /// Save the selected article for later, or unsave it. Unsaving in the
+    /// Saved view removes it from the list.
+    pub fn toggle_bookmark(&mut self) {
+        let Some(article) = self.selected_article().cloned() else {
+            return;
+        };
+        let saved = self.bookmarks.toggle(&article);
+        if let Err(e) = self.bookmarks.save() {
+            self.last_error = Some(format!("Failed to save bookmarks: {:#}", e));
+        }
+
+        if !saved && self.mode == ViewMode::Saved {
+            let index = self.list_state.selected().unwrap_or(0);
+            self.loaded.retain(|a| a.url != article.url);
+            self.articles.retain(|a| a.url != article.url);
+            self.expanded.remove(&article.url);
+            self.list_state
+                .select(Some(index.min(self.articles.len().saturating_sub(1))));
+        }
+    }
+
+    /// Mark every article in the list read
+    pub fn mark_all_read(&mut self) {
+        let urls: Vec<String> = self.articles.iter().map(|a| a.url.clone()).collect();
+        self.mark_read(urls.iter().map(String::as_str));
+    }
+
+    /// The selected article if it's been expanded, or scrolled in the detail
+    /// pane, and not read yet. Merely selecting it in the split layout shows it
+    /// in full too, but doesn't count.
+    fn dwell_candidate(&self) -> Option<&Article> {
+        self.selected_article().filter(|a| {
+            (self.is_expanded(a) || self.detail_read.as_ref() == Some(&a.url)) && !self.is_read(a)
+        })
+    }
+
+    /// Mark the selected article read once it has stayed expanded for `READ_DWELL`
+    fn check_dwell(&mut self, now: Instant) {
+        let current = self.dwell_candidate().map(|a| a.url.clone());
+
+        match (current, self.dwell.take()) {
+            (Some(url), Some((dwelling, since))) if url == dwelling => {
+                if now.duration_since(since) >= READ_DWELL {
+                    self.mark_read([url.as_str()]);
+                } else {
+                    self.dwell = Some((url, since));
                 }
             }
+            (Some(url), _) => self.dwell = Some((url, now)),
+            (None, _) => {}
         }
     }
 
@@ -176,39 +1123,230 @@ impl App {
         self.expanded.clear();
     }
 
-    pub fn quit(&mut self) {
-        self.should_quit = true;
+    /// Open the `/` search prompt
+    pub fn open_search(&mut self) {
+        self.search_origin = self.list_state.selected();
+        self.search_prompt = Some(Prompt::default());
+        self.search.clear();
     }
 
-    pub fn mark_for_refresh(&mut self) {
-        self.needs_refresh = true;
-    }
+    /// Re-run the search after the prompt input changed, selecting the first
+    /// match at or after where the search started
+    pub fn update_search(&mut self) {
+        let Some(input) = self.search_prompt.as_ref().map(|p| p.input.clone()) else {
+            return;
+        };
+        self.search = input;
+
+        let origin = self.search_origin.unwrap_or(0);
+        let matches = self.search_matches();
+        if let Some(prompt) = self.search_prompt.as_mut() {
+            prompt.error =
+                (!self.search.is_empty() && matches.is_empty()).then(|| "No matches".to_string());
+        }
+
+        let target = matches
+            .iter()
+            .find(|&&i| i >= origin)
+            .or(matches.first())
+            .copied();
+        self.list_state.select(target.or(self.search_origin));
+    }
+
+    /// Close the prompt, keeping the query highlighted for `n`/`N` and
+    /// listing it in the Search tab
+    pub fn submit_search(&mut self) {
+        self.search_prompt = None;
+        self.search_origin = None;
+        if !self.search.is_empty() {
+            self.search_results_query = self.search.clone();
+            if self.tab == Tab::Search {
+                self.mark_for_refresh();
+            }
+        }
+    }
+
+    /// Close the prompt and go back to where the search started
+    pub fn cancel_search(&mut self) {
+        self.search_prompt = None;
+        self.search.clear();
+        if let Some(origin) = self.search_origin.take() {
+            self.list_state.select(Some(origin));
+        }
+    }
+
+    pub fn clear_search(&mut self) {
+        self.search.clear();
+    }
+
+    /// Indices of articles matching the active query
+    pub fn search_matches(&self) -> Vec<usize> {
+        if self.search.is_empty() {
+            return Vec::new();
+        }
+        self.articles
+            .iter()
+            .enumerate()
+            .filter(|(_, article)| search::article_matches(article, &self.search))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Jump to the next match, wrapping around
+    pub fn next_match(&mut self) {
+        let matches = self.search_matches();
+        let current = self.list_state.selected().unwrap_or(0);
+        if let Some(&i) = matches.iter().find(|&&i| i > current).or(matches.first()) {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    /// Jump to the previous match, wrapping around
+    pub fn previous_match(&mut self) {
+        let matches = self.search_matches();
+        let current = self.list_state.selected().unwrap_or(0);
+        if let Some(&i) = matches
+            .iter()
+            .rev()
+            .find(|&&i| i < current)
+            .or(matches.last())
+        {
+            self.list_state.select(Some(i));
+        }
+    }
+
+    /// "2/5" for the selected match, or "0/5" when the selection isn't a match
+    pub fn search_position(&self) -> String {
+        let matches = self.search_matches();
+        let current = self.list_state.selected();
+        let position = current
+            .and_then(|c| matches.iter().position(|&i| i == c))
+            .map_or(0, |p| p + 1);
+        format!("{}/{}", position, matches.len())
+    }
+
+    /// Open the tag/source picker with counts over everything loaded
+    pub fn open_facet_picker(&mut self) {
+        self.facet_picker = Some(FacetPicker::new(&self.loaded, &self.filters));
+    }
+
+    pub fn close_facet_picker(&mut self) {
+        self.facet_picker = None;
+    }
+
+    /// Filter the list by what was picked and close the picker
+    pub fn submit_facet_picker(&mut self) {
+        if let Some(picker) = self.facet_picker.take() {
+            self.filters = picker.filters();
+            self.apply_filters();
+        }
+    }
+
+    pub fn toggle_split(&mut self) {
+        self.split = !self.split;
+        self.update_split();
+    }
+
+    /// Track the terminal width, which decides whether the split layout fits
+    pub fn set_terminal_width(&mut self, width: u16) {
+        self.terminal_width = width;
+        self.update_split();
+    }
+
+    fn update_split(&mut self) {
+        self.split_active = self.split && self.terminal_width >= SPLIT_MIN_WIDTH;
+    }
+
+    /// Widen the list pane of the split layout
+    pub fn grow_list(&mut self) {
+        self.split_percent = (self.split_percent + SPLIT_STEP).min(MAX_SPLIT_PERCENT);
+    }
+
+    /// Narrow the list pane of the split layout
+    pub fn shrink_list(&mut self) {
+        self.split_percent = self
+            .split_percent
+            .saturating_sub(SPLIT_STEP)
+            .max(MIN_SPLIT_PERCENT);
+    }
+
+    /// Scroll the detail pane; scrolling counts as reading, like expanding
+    pub fn scroll_detail_down(&mut self) {
+        self.detail_scroll = self.detail_scroll.saturating_add(1);
+        self.focus_detail();
+    }
+
+    pub fn scroll_detail_up(&mut self) {
+        self.detail_scroll = self.detail_scroll.saturating_sub(1);
+        self.focus_detail();
+    }
+
+    fn focus_detail(&mut self) {
+        if let Some(url) = self.selected_article().map(|a| a.url.clone()) {
+            self.detail_read = Some(url);
+        }
+    }
+
+    /// Keep the detail pane from scrolling past its last page
+    pub fn clamp_detail_scroll(&mut self, max: u16) {
+        self.detail_scroll = self.detail_scroll.min(max);
+    }
+
+    /// Start the detail pane at the top whenever another article is selected.
+    /// Called before drawing each frame.
+    pub fn sync_detail(&mut self) {
+        let url = self.selected_article().map(|a| a.url.clone());
+        if url != self.detail_url {
+            self.detail_url = url;
+            self.detail_scroll = 0;
+            self.detail_read = None;
+        }
+    }
+
+    pub fn toggle_log(&mut self) {
+        self.show_log = !self.show_log;
+    }
+
+    /// Whether keys are going into the date or search prompt
+    pub fn is_prompt_open(&self) -> bool {
+        self.date_prompt.is_some() || self.search_prompt.is_some()
+    }
+
+    pub fn quit(&mut self) {
+        self.should_quit = true;
+    }
+
+    pub fn mark_for_refresh(&mut self) {
+        self.needs_refresh = true;
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::article;
 
     fn create_test_app() -> App {
         let mut app = App::new("http://localhost:3000", 50);
-        app.articles = vec![
+        app.replace_articles(vec![
             Article {
                 title: "Article 1".to_string(),
-                url: "https://example.com/1".to_string(),
                 summary: "Summary 1".to_string(),
-                tag: "Tech".to_string(),
                 source: "Source 1".to_string(),
                 published_at: "2024-01-01T00:00:00Z".to_string(),
+                briefing_date: "2024-01-02".to_string(),
+                ..article("https://example.com/1")
             },
             Article {
                 title: "Article 2".to_string(),
-                url: "https://example.com/2".to_string(),
                 summary: "Summary 2".to_string(),
                 tag: "AI".to_string(),
                 source: "Source 2".to_string(),
                 published_at: "2024-01-02T00:00:00Z".to_string(),
+                briefing_date: "2024-01-03".to_string(),
+                ..article("https://example.com/2")
             },
-        ];
+        ]);
         app.loading_state = LoadingState::Loaded;
         app
     }
@@ -245,4 +1383,718 @@ mod tests {
         app.toggle_expand();
         assert!(!app.is_current_expanded());
     }
+
+    #[test]
+    fn test_should_load_more() {
+        let mut app = create_test_app();
+        assert!(!app.should_load_more()); // Nothing left to fetch
+
+        app.has_more = true;
+        assert!(app.should_load_more()); // 2 articles, within threshold
+
+        app.loading_more = true;
+        assert!(!app.should_load_more());
+
+        app.loading_more = false;
+        app.has_more = false;
+        assert!(!app.should_load_more());
+    }
+
+    #[test]
+    fn test_load_more_follows_oldest_article_when_sorted() {
+        let mut app = App::new("http://localhost:3000", 50);
+        let page = |from: u32| -> Vec<Article> {
+            (from..from + 10)
+                .map(|i| Article {
+                    published_at: format!("2026-10-{:02}T00:00:00Z", 28 - i),
+                    ..article(&format!("https://example.com/{}", i))
+                })
+                .collect()
+        };
+        app.replace_articles(page(0));
+        app.loading_state = LoadingState::Loaded;
+        app.has_more = true;
+        app.cycle_sort();
+        assert_eq!(app.sort, SortOrder::Oldest);
+
+        // The oldest article is at the top in Oldest order, not the bottom
+        app.go_to_last();
+        assert!(!app.should_load_more());
+        app.go_to_first();
+        assert!(app.should_load_more());
+
+        // Older articles land above; the selection moves down with its article
+        app.append_articles(page(10));
+        assert_eq!(app.list_state.selected(), Some(10));
+        assert!(!app.should_load_more());
+    }
+
+    #[test]
+    fn test_sections_fold_case() {
+        let mut app = create_test_app();
+        app.articles[0].source = "Hacker News".to_string();
+        app.articles[1].source = "hacker news".to_string();
+        app.sort = SortOrder::Source;
+
+        assert_eq!(app.section_title(&app.articles[1]), "hacker news");
+        assert!(!app.is_section_start(1));
+    }
+
+    #[test]
+    fn test_pagination_and_count_label() {
+        let mut app = create_test_app();
+        assert_eq!(app.count_label(), "2");
+
+        app.apply_pagination(Pagination {
+            page: 1,
+            limit: 2,
+            total: 5,
+            total_pages: 3,
+        });
+        assert!(app.has_more);
+        assert_eq!(app.count_label(), "2 of 5");
+
+        app.apply_pagination(Pagination {
+            page: 3,
+            limit: 2,
+            total: 5,
+            total_pages: 3,
+        });
+        assert!(!app.has_more);
+    }
+
+    #[test]
+    fn test_append_articles_skips_duplicates() {
+        let mut app = create_test_app();
+        let mut page = app.articles.clone();
+        page[0].url = "https://example.com/3".to_string();
+
+        app.append_articles(page);
+        assert_eq!(app.articles.len(), 3);
+        assert_eq!(app.articles[2].url, "https://example.com/3");
+    }
+
+    #[test]
+    fn test_section_navigation() {
+        let mut app = create_test_app();
+        let template = app.articles[0].clone();
+        app.articles = ["2024-01-03", "2024-01-03", "2024-01-02", "2024-01-01"]
+            .iter()
+            .enumerate()
+            .map(|(i, date)| Article {
+                url: format!("https://example.com/{}", i),
+                briefing_date: date.to_string(),
+                ..template.clone()
+            })
+            .collect();
+
+        assert!(app.is_section_start(0));
+        assert!(!app.is_section_start(1));
+        assert!(app.is_section_start(2));
+
+        app.next_section();
+        assert_eq!(app.list_state.selected(), Some(2));
+        app.next_section();
+        assert_eq!(app.list_state.selected(), Some(3));
+        app.next_section();
+        assert_eq!(app.list_state.selected(), Some(3)); // Already in last section
+
+        app.previous_section();
+        assert_eq!(app.list_state.selected(), Some(2));
+
+        app.list_state.select(Some(1));
+        app.previous_section();
+        assert_eq!(app.list_state.selected(), Some(0)); // Start of current section
+    }
+
+    #[test]
+    fn test_offline_loads_from_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = ArticleCache::open(dir.path().join("articles.json"));
+        cache.insert(&create_test_app().articles);
+
+        let mut app = App::new("http://localhost:3000", 1);
+        app.cache = Some(cache);
+        app.offline = true;
+
+        app.load_initial();
+        assert!(app.take_fetch_jobs().is_empty()); // Never touches the network
+        assert_eq!(app.loading_state, LoadingState::Loaded);
+        assert_eq!(app.articles.len(), 1); // Latest honors --limit
+        assert_eq!(app.articles[0].url, "https://example.com/2"); // Newest first
+        assert_eq!(app.stale_reason.as_deref(), Some("Offline"));
+
+        app.mode = ViewMode::Range(DateRange::parse("2024-01-02").unwrap());
+        app.load_initial();
+        assert_eq!(app.articles.len(), 1);
+        assert_eq!(app.articles[0].url, "https://example.com/1");
+    }
+
+    #[test]
+    fn test_reload_queues_fetch_and_applies_result() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.load_initial();
+        assert!(app.is_fetching());
+        assert_eq!(app.loading_state, LoadingState::Loading);
+
+        let jobs = app.take_fetch_jobs();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].kind, FetchKind::Reload);
+        assert_eq!(jobs[0].request, FetchRequest::Latest { page: 1, limit: 50 });
+
+        app.handle_fetch_result(FetchResult {
+            generation: jobs[0].generation,
+            kind: FetchKind::Reload,
+            result: Ok(Page {
+                articles: create_test_app().articles,
+                ..Page::default()
+            }),
+        });
+        assert!(!app.is_fetching());
+        assert_eq!(app.loading_state, LoadingState::Loaded);
+        assert_eq!(app.articles.len(), 2);
+    }
+
+    #[test]
+    fn test_poll_counts_new_articles_without_touching_list() {
+        let mut app = create_test_app();
+        app.list_state.select(Some(1));
+        app.expanded.insert("https://example.com/2".to_string());
+
+        app.poll_for_new();
+        let job = app.take_fetch_jobs().remove(0);
+        assert_eq!(job.kind, FetchKind::Poll);
+
+        let mut articles = app.articles.clone();
+        articles.insert(
+            0,
+            Article {
+                url: "https://example.com/3".to_string(),
+                published_at: "2024-01-03T00:00:00Z".to_string(),
+                ..app.articles[0].clone()
+            },
+        );
+        app.handle_fetch_result(FetchResult {
+            generation: job.generation,
+            kind: FetchKind::Poll,
+            result: Ok(Page {
+                articles,
+                ..Page::default()
+            }),
+        });
+
+        assert_eq!(app.new_articles, 1);
+        assert_eq!(app.articles.len(), 2);
+        assert_eq!(app.list_state.selected(), Some(1));
+        assert!(app.is_current_expanded());
+    }
+
+    #[test]
+    fn test_reload_keeps_selection_and_expansion_by_url() {
+        let mut app = create_test_app();
+        app.list_state.select(Some(1));
+        app.toggle_expand();
+
+        // A new article lands on top, shifting everything down by one
+        let mut articles = app.articles.clone();
+        articles.insert(
+            0,
+            Article {
+                url: "https://example.com/3".to_string(),
+                ..app.articles[0].clone()
+            },
+        );
+
+        app.refresh();
+        let job = app.take_fetch_jobs().remove(0);
+        app.handle_fetch_result(FetchResult {
+            generation: job.generation,
+            kind: FetchKind::Reload,
+            result: Ok(Page {
+                articles,
+                ..Page::default()
+            }),
+        });
+
+        assert_eq!(app.list_state.selected(), Some(2));
+        assert_eq!(app.selected_article().unwrap().url, "https://example.com/2");
+        assert!(app.is_current_expanded());
+        assert_eq!(app.expanded.len(), 1);
+
+        // Articles that drop out lose their expansion state
+        app.refresh();
+        let job = app.take_fetch_jobs().remove(0);
+        app.handle_fetch_result(FetchResult {
+            generation: job.generation,
+            kind: FetchKind::Reload,
+            result: Ok(Page {
+                articles: app.articles[..1].to_vec(),
+                ..Page::default()
+            }),
+        });
+        assert_eq!(app.list_state.selected(), Some(0));
+        assert!(app.expanded.is_empty());
+    }
+
+    #[test]
+    fn test_filters_apply_to_fetched_articles() {
+        let mut app = create_test_app();
+        let articles = app.articles.clone();
+        app.filters = Filters {
+            exclude_tags: vec!["ai".to_string()],
+            ..Filters::default()
+        };
+
+        app.refresh();
+        let job = app.take_fetch_jobs().remove(0);
+        app.handle_fetch_result(FetchResult {
+            generation: job.generation,
+            kind: FetchKind::Reload,
+            result: Ok(Page {
+                articles,
+                pagination: Some(Pagination {
+                    page: 1,
+                    limit: 50,
+                    total: 2,
+                    total_pages: 1,
+                }),
+                ..Page::default()
+            }),
+        });
+
+        assert_eq!(app.articles.len(), 1);
+        assert_eq!(app.articles[0].tag, "Tech");
+        // The server's total includes the hidden article
+        assert_eq!(app.count_label(), "1");
+    }
+
+    #[test]
+    fn test_facet_picker_filters_loaded_articles() {
+        let mut app = create_test_app();
+        app.list_state.select(Some(1));
+
+        app.open_facet_picker();
+        let picker = app.facet_picker.as_mut().unwrap();
+        assert_eq!(picker.facets.len(), 4);
+        // Exclude the first tag, AI
+        picker.toggle();
+        picker.toggle();
+        app.submit_facet_picker();
+
+        assert!(app.facet_picker.is_none());
+        assert_eq!(app.filters.exclude_tags, vec!["AI"]);
+        assert_eq!(app.articles.len(), 1);
+        assert_eq!(app.articles[0].tag, "Tech");
+        assert_eq!(app.list_state.selected(), Some(0));
+
+        // Counts still cover hidden articles, and clearing brings them back
+        app.open_facet_picker();
+        let picker = app.facet_picker.as_mut().unwrap();
+        assert_eq!(picker.facets[0].count, 1);
+        assert_eq!(picker.facets[0].name, "AI");
+        picker.clear();
+        app.submit_facet_picker();
+        assert_eq!(app.articles.len(), 2);
+
+        // Filters persist across a refresh
+        app.filters.tags = vec!["tech".to_string()];
+        app.apply_filters();
+        app.refresh();
+        let job = app.take_fetch_jobs().remove(0);
+        let articles = app.loaded.clone();
+        app.handle_fetch_result(FetchResult {
+            generation: job.generation,
+            kind: FetchKind::Reload,
+            result: Ok(Page {
+                articles,
+                ..Page::default()
+            }),
+        });
+        assert_eq!(app.articles.len(), 1);
+        assert_eq!(app.filters.tags, vec!["tech"]);
+    }
+
+    #[test]
+    fn test_sort_orders_keep_selection() {
+        let mut app = create_test_app();
+        let template = app.articles[0].clone();
+        app.replace_articles(
+            [
+                ("b", "Tech", "Lobsters", "2024-01-03T00:00:00Z"),
+                ("c", "AI", "arXiv", "2024-01-02T00:00:00Z"),
+                ("a", "AI", "Hacker News", "2024-01-01T00:00:00Z"),
+            ]
+            .iter()
+            .map(|(url, tag, source, published_at)| Article {
+                url: url.to_string(),
+                tag: tag.to_string(),
+                source: source.to_string(),
+                published_at: published_at.to_string(),
+                ..template.clone()
+            })
+            .collect(),
+        );
+        app.list_state.select(Some(1));
+        let urls =
+            |app: &App| -> Vec<String> { app.articles.iter().map(|a| a.url.clone()).collect() };
+
+        app.cycle_sort();
+        assert_eq!(app.sort, SortOrder::Oldest);
+        assert_eq!(urls(&app), vec!["a", "c", "b"]);
+        assert_eq!(app.selected_article().unwrap().url, "c");
+
+        app.cycle_sort();
+        assert_eq!(urls(&app), vec!["c", "a", "b"]);
+        assert_eq!(app.section_title(&app.articles[0]), "arxiv");
+        assert!(app.is_section_start(1));
+
+        app.cycle_sort();
+        assert_eq!(urls(&app), vec!["c", "a", "b"]);
+        assert!(!app.is_section_start(1));
+
+        app.read.mark_read(["c"]);
+        app.cycle_sort();
+        assert_eq!(app.sort, SortOrder::UnreadFirst);
+        assert_eq!(urls(&app), vec!["b", "a", "c"]);
+        assert_eq!(app.section_title(&app.articles[2]), "Read");
+        assert_eq!(app.selected_article().unwrap().url, "c");
+
+        // New pages are merged into the current order
+        let mut page = vec![app.articles[0].clone()];
+        page[0].url = "d".to_string();
+        page[0].published_at = "2023-12-31T00:00:00Z".to_string();
+        app.append_articles(page);
+        assert_eq!(urls(&app), vec!["b", "a", "d", "c"]);
+
+        app.cycle_sort();
+        assert_eq!(app.sort, SortOrder::Newest);
+        assert_eq!(urls(&app), vec!["b", "c", "a", "d"]);
+    }
+
+    #[test]
+    fn test_read_tracking() {
+        let mut app = create_test_app();
+        assert_eq!(app.unread_count(), 2);
+
+        app.toggle_read();
+        assert!(app.is_read(&app.articles[0]));
+        assert_eq!(app.unread_count(), 1);
+        app.toggle_read();
+        assert_eq!(app.unread_count(), 2);
+
+        app.mark_all_read();
+        assert_eq!(app.unread_count(), 0);
+    }
+
+    #[test]
+    fn test_prompt_open() {
+        let mut app = create_test_app();
+        assert!(!app.is_prompt_open());
+        app.open_search();
+        assert!(app.is_prompt_open());
+    }
+
+    #[test]
+    fn test_expanded_article_is_read_after_dwell() {
+        let mut app = create_test_app();
+        let start = Instant::now();
+
+        // Collapsed articles are never read by dwelling
+        app.check_dwell(start);
+        app.check_dwell(start + READ_DWELL);
+        assert!(!app.is_read(&app.articles[0]));
+
+        app.toggle_expand();
+        app.check_dwell(start);
+        app.check_dwell(start + READ_DWELL / 2);
+        assert!(!app.is_read(&app.articles[0]));
+
+        // Moving away restarts the clock
+        app.next();
+        app.check_dwell(start + READ_DWELL);
+        app.previous();
+        app.check_dwell(start + READ_DWELL);
+        assert!(!app.is_read(&app.articles[0]));
+
+        app.check_dwell(start + READ_DWELL * 2);
+        assert!(app.is_read(&app.articles[0]));
+        assert!(!app.is_read(&app.articles[1]));
+    }
+
+    #[test]
+    fn test_dwell_ticks_without_fetching() {
+        let mut app = create_test_app();
+        assert!(!app.is_fetching());
+        assert!(!app.needs_tick());
+
+        // The event loop ticks while `needs_tick` holds, as it does in main
+        app.toggle_expand();
+        let tick = Duration::from_millis(100);
+        let mut now = Instant::now();
+        let mut ticks = 0;
+        while app.needs_tick() && ticks < 1000 {
+            app.check_dwell(now);
+            now += tick;
+            ticks += 1;
+        }
+        assert!(app.is_read(&app.articles[0]));
+        assert_eq!(
+            ticks,
+            (READ_DWELL.as_millis() / tick.as_millis()) as usize + 1
+        );
+    }
+
+    #[test]
+    fn test_split_layout() {
+        let mut app = create_test_app();
+        assert!(!app.needs_tick());
+
+        app.set_terminal_width(SPLIT_MIN_WIDTH - 1);
+        assert!(!app.split_active);
+        app.set_terminal_width(SPLIT_MIN_WIDTH);
+        assert!(app.split_active);
+
+        // Only showing the article in the detail pane doesn't mark it read
+        assert!(!app.needs_tick());
+
+        // Scrolling it does
+        app.scroll_detail_down();
+        assert!(app.needs_tick());
+        let start = Instant::now();
+        app.check_dwell(start);
+        app.check_dwell(start + READ_DWELL);
+        assert!(app.is_read(&app.articles[0]));
+        assert!(!app.needs_tick());
+        // without expanding it in the list
+        assert!(!app.is_expanded(&app.articles[0]));
+
+        for _ in 0..10 {
+            app.grow_list();
+        }
+        assert_eq!(app.split_percent, MAX_SPLIT_PERCENT);
+        for _ in 0..20 {
+            app.shrink_list();
+        }
+        assert_eq!(app.split_percent, MIN_SPLIT_PERCENT);
+
+        // Scrolling starts over for another article
+        app.sync_detail();
+        app.scroll_detail_down();
+        app.scroll_detail_down();
+        app.sync_detail();
+        assert_eq!(app.detail_scroll, 2);
+        app.clamp_detail_scroll(1);
+        assert_eq!(app.detail_scroll, 1);
+        app.next();
+        app.sync_detail();
+        assert_eq!(app.detail_scroll, 0);
+        // The next article isn't being read until it's scrolled too
+        assert!(!app.needs_tick());
+        app.scroll_detail_up();
+        assert_eq!(app.detail_scroll, 0);
+
+        app.toggle_split();
+        assert!(!app.split);
+        assert!(!app.split_active);
+    }
+
+    #[test]
+    fn test_bookmarks_and_saved_view() {
+        let mut app = create_test_app();
+        app.toggle_bookmark();
+        app.next();
+        app.toggle_bookmark();
+        assert!(app.is_bookmarked(&app.articles[0]));
+
+        app.toggle_saved();
+        assert_eq!(app.mode, ViewMode::Saved);
+        assert!(app.articles.is_empty());
+        app.load_initial();
+        assert!(app.take_fetch_jobs().is_empty());
+        assert_eq!(app.loading_state, LoadingState::Loaded);
+        // Most recently saved first
+        let urls: Vec<&str> = app.articles.iter().map(|a| a.url.as_str()).collect();
+        assert_eq!(urls, vec!["https://example.com/2", "https://example.com/1"]);
+
+        // Removing drops the article from the view and keeps a valid selection
+        app.go_to_last();
+        app.toggle_bookmark();
+        assert_eq!(app.articles.len(), 1);
+        assert_eq!(app.list_state.selected(), Some(0));
+        assert!(!app.bookmarks.contains("https://example.com/1"));
+
+        app.toggle_saved();
+        assert_eq!(app.mode, ViewMode::Latest);
+    }
+
+    #[test]
+    fn test_tabs_keep_their_own_lists() {
+        let mut app = create_test_app();
+        app.next();
+        app.filters.exclude_sources = vec!["Nowhere".to_string()];
+        app.default_filters.tags = vec!["AI".to_string()];
+
+        app.switch_tab(Tab::Timeline);
+        assert_eq!(app.tab, Tab::Timeline);
+        assert_eq!(app.mode, ViewMode::Timeline);
+        assert!(app.articles.is_empty());
+        assert_eq!(app.filters.tags, vec!["AI"]);
+        assert!(app.needs_refresh);
+
+        // A fetch started here is abandoned when leaving, and redone on return
+        app.refresh();
+        let job = app.take_fetch_jobs().remove(0);
+        app.switch_tab(Tab::Latest);
+        app.handle_fetch_result(FetchResult {
+            generation: job.generation,
+            kind: FetchKind::Reload,
+            result: Ok(Page::default()),
+        });
+        assert_eq!(app.articles.len(), 2);
+        assert_eq!(app.list_state.selected(), Some(1));
+        assert_eq!(app.filters.exclude_sources, vec!["Nowhere"]);
+
+        app.needs_refresh = false;
+        app.next_tab();
+        assert_eq!(app.tab, Tab::Timeline);
+        assert!(app.needs_refresh);
+
+        app.previous_tab();
+        app.previous_tab();
+        assert_eq!(app.tab, Tab::Search);
+    }
+
+    #[test]
+    fn test_search_tab_lists_matches_from_every_tab() {
+        let mut app = create_test_app();
+        let mut saved = app.articles[0].clone();
+        saved.url = "https://example.com/saved".to_string();
+        saved.title = "Saved summary".to_string();
+        app.bookmarks.toggle(&saved);
+
+        app.switch_tab(Tab::Search);
+        app.load_initial();
+        assert!(app.articles.is_empty());
+
+        app.open_search();
+        app.search_prompt.as_mut().unwrap().input = "summary".to_string();
+        app.update_search();
+        app.submit_search();
+        assert_eq!(app.search_results_query, "summary");
+        app.load_initial();
+
+        let mut urls: Vec<&str> = app.articles.iter().map(|a| a.url.as_str()).collect();
+        urls.sort();
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/1",
+                "https://example.com/2",
+                "https://example.com/saved"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_incremental_search() {
+        let mut app = create_test_app();
+        app.articles.push(Article {
+            title: "第三篇 Article".to_string(),
+            url: "https://example.com/3".to_string(),
+            ..app.articles[0].clone()
+        });
+        app.list_state.select(Some(1));
+
+        app.open_search();
+        for c in "ARTICLE".chars() {
+            app.search_prompt.as_mut().unwrap().input.push(c);
+            app.update_search();
+        }
+        // The first match at or after the starting position
+        assert_eq!(app.list_state.selected(), Some(1));
+        assert_eq!(app.search_matches(), vec![0, 1, 2]);
+        app.submit_search();
+        assert!(app.search_prompt.is_none());
+
+        app.next_match();
+        assert_eq!(app.list_state.selected(), Some(2));
+        app.next_match();
+        assert_eq!(app.list_state.selected(), Some(0));
+        app.previous_match();
+        assert_eq!(app.list_state.selected(), Some(2));
+        assert_eq!(app.search_position(), "3/3");
+
+        app.open_search();
+        app.search_prompt.as_mut().unwrap().input = "三篇".to_string();
+        app.update_search();
+        assert_eq!(app.search_matches(), vec![2]);
+
+        app.search_prompt.as_mut().unwrap().input = "nothing".to_string();
+        app.update_search();
+        assert_eq!(
+            app.search_prompt.as_ref().unwrap().error.as_deref(),
+            Some("No matches")
+        );
+        assert_eq!(app.list_state.selected(), Some(2));
+
+        app.cancel_search();
+        assert!(app.search.is_empty());
+        assert_eq!(app.list_state.selected(), Some(2));
+    }
+
+    #[test]
+    fn test_stale_fetch_result_is_ignored() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.load_initial();
+        let old = app.take_fetch_jobs().remove(0);
+
+        // Switching modes starts a new generation before the old fetch lands
+        app.toggle_mode();
+        app.refresh();
+
+        app.handle_fetch_result(FetchResult {
+            generation: old.generation,
+            kind: FetchKind::Reload,
+            result: Ok(Page {
+                articles: create_test_app().articles,
+                ..Page::default()
+            }),
+        });
+        assert!(app.articles.is_empty());
+        assert_eq!(app.loading_state, LoadingState::Loading);
+    }
+
+    #[test]
+    fn test_next_does_not_wrap_while_more_pages() {
+        let mut app = create_test_app();
+        app.mode = ViewMode::Timeline;
+        app.has_more = true;
+
+        app.next();
+        app.next();
+        assert_eq!(app.list_state.selected(), Some(1));
+    }
+
+    #[test]
+    fn test_submit_date_prompt() {
+        let mut app = create_test_app();
+
+        app.open_date_prompt();
+        app.date_prompt.as_mut().unwrap().input = "2026-10-05..2026-10-01".to_string();
+        app.submit_date_prompt();
+        let prompt = app.date_prompt.as_ref().unwrap();
+        assert!(prompt
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("Invalid date range"));
+        assert_eq!(app.mode, ViewMode::Latest);
+
+        app.date_prompt.as_mut().unwrap().input = "2026-10-01..2026-10-05".to_string();
+        app.submit_date_prompt();
+        assert!(app.date_prompt.is_none());
+        assert_eq!(app.mode.label(), "2026-10-01..2026-10-05");
+        assert!(app.articles.is_empty());
+        assert!(app.needs_refresh);
+    }
 }
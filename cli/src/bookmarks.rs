@@ -0,0 +1,143 @@
+use crate::api::Article;
+use crate::json_file;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+const BOOKMARKS_FILE: &str = "bookmarks.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SavedArticle {
+    article: Article,
+    saved_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BookmarksFile {
+    saved: Vec<SavedArticle>,
+}
+
+/// Articles saved for later, stored whole so they outlive the API's
+/// `/api/latest` window. Without a path they only live in memory.
+#[derive(Debug, Default)]
+pub struct Bookmarks {
+    path: Option<PathBuf>,
+    /// Most recently saved first
+    saved: Vec<SavedArticle>,
+}
+
+impl Bookmarks {
+    /// Default location: `$XDG_DATA_HOME/ptlk/bookmarks.json` (or the platform equivalent)
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("ptlk").join(BOOKMARKS_FILE))
+    }
+
+    /// Load the bookmarks at `path`. A missing file yields an empty list, a
+    /// corrupt one an error.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let saved = json_file::load::<BookmarksFile>(&path)?
+            .unwrap_or_default()
+            .saved;
+
+        Ok(Self {
+            path: Some(path),
+            saved,
+        })
+    }
+
+    /// Saved articles, most recently saved first
+    pub fn articles(&self) -> Vec<Article> {
+        self.saved.iter().map(|s| s.article.clone()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.saved.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.saved.is_empty()
+    }
+
+    pub fn contains(&self, url: &str) -> bool {
+        self.saved.iter().any(|s| s.article.url == url)
+    }
+
+    /// Save `article`, or remove it if it's already saved.
+    /// Returns whether it's saved now.
+    pub fn toggle(&mut self, article: &Article) -> bool {
+        if self.remove(&article.url) {
+            return false;
+        }
+        self.saved.insert(
+            0,
+            SavedArticle {
+                article: article.clone(),
+                saved_at: Utc::now(),
+            },
+        );
+        true
+    }
+
+    /// Returns whether `url` was saved
+    pub fn remove(&mut self, url: &str) -> bool {
+        let before = self.saved.len();
+        self.saved.retain(|s| s.article.url != url);
+        self.saved.len() != before
+    }
+
+    /// Write the bookmarks to disk, replacing the file atomically. A no-op
+    /// for in-memory bookmarks.
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let file = BookmarksFile {
+            saved: self.saved.clone(),
+        };
+        json_file::write_json_atomic(path, &file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::article;
+    use std::fs;
+
+    #[test]
+    fn test_bookmarks_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(BOOKMARKS_FILE);
+
+        let mut bookmarks = Bookmarks::open(&path).unwrap();
+        assert!(bookmarks.is_empty());
+
+        assert!(bookmarks.toggle(&article("https://example.com/1")));
+        assert!(bookmarks.toggle(&article("https://example.com/2")));
+        bookmarks.save().unwrap();
+
+        let mut bookmarks = Bookmarks::open(&path).unwrap();
+        assert_eq!(bookmarks.len(), 2);
+        let articles = bookmarks.articles();
+        assert_eq!(articles[0].url, "https://example.com/2");
+        assert_eq!(articles[0].summary, "Summary");
+
+        assert!(!bookmarks.toggle(&article("https://example.com/2")));
+        assert!(!bookmarks.contains("https://example.com/2"));
+        assert!(bookmarks.remove("https://example.com/1"));
+        assert!(!bookmarks.remove("https://example.com/1"));
+        assert!(bookmarks.is_empty());
+    }
+
+    #[test]
+    fn test_corrupt_bookmarks_are_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(BOOKMARKS_FILE);
+        fs::write(&path, "not json").unwrap();
+
+        let err = Bookmarks::open(&path).unwrap_err();
+        assert!(err.to_string().starts_with("Failed to parse"));
+    }
+}
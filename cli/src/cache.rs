@@ -0,0 +1,161 @@
+use crate::api::Article;
+use crate::json_file;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Oldest articles (by publication time) are dropped beyond this many entries
+const MAX_CACHED_ARTICLES: usize = 2000;
+
+const CACHE_FILE: &str = "articles.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedArticle {
+    article: Article,
+    fetched_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    articles: HashMap<String, CachedArticle>,
+}
+
+/// Articles from previous successful fetches, keyed by URL and stored as JSON
+/// under the user's cache directory
+pub struct ArticleCache {
+    path: PathBuf,
+    entries: HashMap<String, CachedArticle>,
+}
+
+impl ArticleCache {
+    /// Default location: `$XDG_CACHE_HOME/ptlk/articles.json` (or the platform equivalent)
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("ptlk").join(CACHE_FILE))
+    }
+
+    /// Load the cache at `path`. A missing or unreadable file yields an empty cache.
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let entries = json_file::load::<CacheFile>(&path)
+            .ok()
+            .flatten()
+            .map(|file| file.articles)
+            .unwrap_or_default();
+
+        Self { path, entries }
+    }
+
+    /// Every cached article, newest first
+    pub fn articles(&self) -> Vec<Article> {
+        let mut articles: Vec<Article> = self
+            .entries
+            .values()
+            .map(|entry| entry.article.clone())
+            .collect();
+        articles.sort_by(|a, b| b.published_at.cmp(&a.published_at));
+        articles
+    }
+
+    /// Add or replace articles by URL
+    pub fn insert(&mut self, articles: &[Article]) {
+        let now = Utc::now();
+        for article in articles {
+            self.entries.insert(
+                article.url.clone(),
+                CachedArticle {
+                    article: article.clone(),
+                    fetched_at: now,
+                },
+            );
+        }
+        self.prune();
+    }
+
+    fn prune(&mut self) {
+        if self.entries.len() <= MAX_CACHED_ARTICLES {
+            return;
+        }
+
+        let mut by_age: Vec<(String, String)> = self
+            .entries
+            .iter()
+            .map(|(url, entry)| (entry.article.published_at.clone(), url.clone()))
+            .collect();
+        by_age.sort();
+
+        let excess = self.entries.len() - MAX_CACHED_ARTICLES;
+        for (_, url) in by_age.into_iter().take(excess) {
+            self.entries.remove(&url);
+        }
+    }
+
+    /// Write the cache to disk, replacing the file atomically
+    pub fn save(&self) -> Result<()> {
+        let file = CacheFile {
+            articles: self.entries.clone(),
+        };
+        json_file::write_json_atomic(&self.path, &file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+    use std::fs;
+
+    fn article(url: &str, published_at: &str) -> Article {
+        Article {
+            published_at: published_at.to_string(),
+            ..test_support::article(url)
+        }
+    }
+
+    #[test]
+    fn test_cache_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(CACHE_FILE);
+
+        let mut cache = ArticleCache::open(&path);
+        assert!(cache.articles().is_empty());
+
+        cache.insert(&[
+            article("https://example.com/old", "2026-10-14T08:00:00+00:00"),
+            article("https://example.com/new", "2026-10-15T08:00:00+00:00"),
+        ]);
+        cache.save().unwrap();
+
+        let cache = ArticleCache::open(&path);
+        let urls: Vec<_> = cache.articles().into_iter().map(|a| a.url).collect();
+        assert_eq!(
+            urls,
+            vec!["https://example.com/new", "https://example.com/old"]
+        );
+    }
+
+    #[test]
+    fn test_cache_keys_by_url() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = ArticleCache::open(dir.path().join(CACHE_FILE));
+
+        let mut updated = article("https://example.com/1", "2026-10-15T08:00:00+00:00");
+        cache.insert(std::slice::from_ref(&updated));
+        updated.title = "Updated".to_string();
+        cache.insert(&[updated]);
+
+        let articles = cache.articles();
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].title, "Updated");
+    }
+
+    #[test]
+    fn test_cache_ignores_corrupt_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CACHE_FILE);
+        fs::write(&path, "not json").unwrap();
+
+        assert!(ArticleCache::open(&path).articles().is_empty());
+    }
+}
@@ -0,0 +1,441 @@
+use crate::api::{ApiClient, Article};
+use crate::cache::ArticleCache;
+use crate::config::{Config, ListArgs, ListFormat, Source};
+use crate::fetch::{self, FetchRequest};
+use crate::ui::{display_width, format_time};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use clap::ValueEnum;
+use std::io::{self, BufWriter, Write};
+
+/// Safety cap when following `/api/latest` pages
+const MAX_LIST_PAGES: u32 = 20;
+
+/// `ptlk list`: print articles to stdout
+pub async fn list(config: &Config, args: &ListArgs) -> Result<()> {
+    let since = args
+        .since
+        .as_deref()
+        .map(|since| parse_since(since, Utc::now()))
+        .transpose()?;
+
+    let articles = load_articles(config).await?;
+    let articles = filter_articles(articles, args, since);
+
+    write_stdout(|out| write_list(out, &articles, args.format))
+}
+
+/// Run `write` against a buffered stdout. A closed pipe (`ptlk list | head`)
+/// is not an error.
+pub fn write_stdout(write: impl FnOnce(&mut dyn Write) -> io::Result<()>) -> Result<()> {
+    let mut out = BufWriter::new(io::stdout().lock());
+    match write(&mut out).and_then(|_| out.flush()) {
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+/// Articles for the non-interactive commands: every page of `/api/latest`,
+/// the first timeline page with `--timeline`, or the `--from`/`--to` range.
+/// `--offline` reads the local cache instead. The config file's `[filters]`
+/// apply either way.
+pub async fn load_articles(config: &Config) -> Result<Vec<Article>> {
+    let mut articles = fetch_articles(config).await?;
+    articles.retain(|a| config.filters.matches(a));
+    Ok(articles)
+}
+
+async fn fetch_articles(config: &Config) -> Result<Vec<Article>> {
+    let range = config.date_range()?;
+    let mut cache = ArticleCache::default_path().map(ArticleCache::open);
+
+    if config.offline {
+        let articles = cache.map(|cache| cache.articles()).unwrap_or_default();
+        return Ok(match range {
+            Some(range) => {
+                let (start, end) = (range.start.to_string(), range.end.to_string());
+                articles
+                    .into_iter()
+                    .filter(|a| a.briefing_date >= start && a.briefing_date <= end)
+                    .collect()
+            }
+            None => articles,
+        });
+    }
+
+    let client = ApiClient::new(&config.api_url).with_retry_policy(config.retry_policy());
+    let articles = if let Some(range) = range {
+        fetch::run(&client, FetchRequest::Range(range))
+            .await?
+            .articles
+    } else if config.timeline {
+        let request = FetchRequest::Timeline {
+            cursor: None,
+            limit: config.limit,
+        };
+        fetch::run(&client, request).await?.articles
+    } else {
+        let mut articles = Vec::new();
+        for page in 1..=MAX_LIST_PAGES {
+            let request = FetchRequest::Latest {
+                page,
+                limit: config.limit,
+            };
+            let page = fetch::run(&client, request).await?;
+            articles.extend(page.articles);
+            if !page.has_more {
+                break;
+            }
+        }
+        articles
+    };
+
+    if let Some(cache) = cache.as_mut() {
+        cache.insert(&articles);
+        let _ = cache.save();
+    }
+
+    Ok(articles)
+}
+
+/// `ptlk config show`
+pub fn show_config(config: &Config) -> Result<()> {
+    write_stdout(|out| write_config(out, config))
+}
+
+/// The effective configuration as TOML, each value annotated with its source
+pub fn write_config(out: &mut dyn Write, config: &Config) -> io::Result<()> {
+    match &config.loaded_file {
+        Some(path) => writeln!(out, "# Config file: {}", path.display())?,
+        None => match Config::default_config_path() {
+            Some(path) => writeln!(out, "# Config file: {} (not found)", path.display())?,
+            None => writeln!(out, "# Config file: none")?,
+        },
+    }
+    writeln!(out)?;
+
+    let line = |out: &mut dyn Write, key: &str, value: String, source: Source| {
+        writeln!(out, "{:<40} # {}", format!("{} = {}", key, value), source)
+    };
+    let list = |values: &[String]| toml::Value::from(values.to_vec()).to_string();
+
+    line(
+        out,
+        "api_url",
+        toml::Value::from(config.api_url.as_str()).to_string(),
+        config.source("api_url"),
+    )?;
+    line(
+        out,
+        "limit",
+        config.limit.to_string(),
+        config.source("limit"),
+    )?;
+    line(
+        out,
+        "refresh_interval",
+        config.refresh_interval.to_string(),
+        config.source("refresh_interval"),
+    )?;
+    let theme = config
+        .theme
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default();
+    line(
+        out,
+        "theme",
+        toml::Value::from(theme).to_string(),
+        config.source("theme"),
+    )?;
+
+    let source = config.source("filters");
+    writeln!(out)?;
+    writeln!(out, "[filters]")?;
+    line(out, "tags", list(&config.filters.tags), source)?;
+    line(out, "sources", list(&config.filters.sources), source)?;
+    line(
+        out,
+        "exclude_tags",
+        list(&config.filters.exclude_tags),
+        source,
+    )?;
+    line(
+        out,
+        "exclude_sources",
+        list(&config.filters.exclude_sources),
+        source,
+    )?;
+
+    writeln!(out)?;
+    writeln!(out, "[keybindings]")?;
+    for (action, key) in config.keymap.bindings() {
+        line(
+            out,
+            action.name(),
+            toml::Value::from(key.to_string()).to_string(),
+            config.source(&format!("keybindings.{}", action.name())),
+        )?;
+    }
+    Ok(())
+}
+
+/// Parse `--since`: a date (UTC midnight), an RFC 3339 timestamp, or an age
+/// such as `30m`, `12h` or `3d` counted back from `now`
+pub fn parse_since(input: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let input = input.trim();
+
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).expect("midnight").and_utc());
+    }
+    if let Ok(time) = DateTime::parse_from_rfc3339(input) {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    let Some(unit) = input.chars().last() else {
+        bail!("--since needs a value");
+    };
+    let amount: i64 = input[..input.len() - unit.len_utf8()]
+        .parse()
+        .with_context(|| format!("Invalid --since '{}'", input))?;
+    if amount <= 0 {
+        bail!("Invalid --since '{}': the age must be positive", input);
+    }
+    let age = match unit {
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => bail!(
+            "Invalid --since '{}': expected YYYY-MM-DD, RFC 3339, or an age like 12h",
+            input
+        ),
+    };
+    age.and_then(|age| now.checked_sub_signed(age))
+        .with_context(|| format!("Invalid --since '{}': too far back", input))
+}
+
+/// Apply `--tag`, `--source` and `--since`
+pub fn filter_articles(
+    articles: Vec<Article>,
+    args: &ListArgs,
+    since: Option<DateTime<Utc>>,
+) -> Vec<Article> {
+    let matches_any = |values: &[String], field: &str| {
+        values.is_empty() || values.iter().any(|v| v.eq_ignore_ascii_case(field))
+    };
+
+    articles
+        .into_iter()
+        .filter(|a| matches_any(&args.tag, &a.tag))
+        .filter(|a| matches_any(&args.source, &a.source))
+        .filter(|a| match since {
+            Some(since) => DateTime::parse_from_rfc3339(&a.published_at)
+                .is_ok_and(|published| published >= since),
+            None => true,
+        })
+        .collect()
+}
+
+pub fn write_list(out: &mut dyn Write, articles: &[Article], format: ListFormat) -> io::Result<()> {
+    match format {
+        ListFormat::Plain => write_plain(out, articles),
+        ListFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, articles)?;
+            writeln!(out)
+        }
+        ListFormat::Ndjson => {
+            for article in articles {
+                serde_json::to_writer(&mut *out, article)?;
+                writeln!(out)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// One article per line: time, tag, source and title in aligned columns, then the URL
+fn write_plain(out: &mut dyn Write, articles: &[Article]) -> io::Result<()> {
+    let tag_width = articles
+        .iter()
+        .map(|a| display_width(&a.tag))
+        .max()
+        .unwrap_or(0);
+    let source_width = articles
+        .iter()
+        .map(|a| display_width(&a.source))
+        .max()
+        .unwrap_or(0);
+    let title_width = articles
+        .iter()
+        .map(|a| display_width(&a.title))
+        .max()
+        .unwrap_or(0);
+
+    for article in articles {
+        writeln!(
+            out,
+            "{}  {}  {}  {}  {}",
+            format_time(&article.published_at),
+            pad(&article.tag, tag_width),
+            pad(&article.source, source_width),
+            pad(&article.title, title_width),
+            article.url
+        )?;
+    }
+    Ok(())
+}
+
+/// Left-align `text` to `width` display columns (CJK-aware, unlike `{:<width$}`)
+fn pad(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    fn article(title: &str, tag: &str, source: &str, published_at: &str) -> Article {
+        Article {
+            title: title.to_string(),
+            tag: tag.to_string(),
+            source: source.to_string(),
+            published_at: published_at.to_string(),
+            ..test_support::article(&format!("https://example.com/{}", title))
+        }
+    }
+
+    #[test]
+    fn test_parse_since() {
+        let now = DateTime::parse_from_rfc3339("2026-10-17T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            parse_since("2026-10-16", now).unwrap().to_rfc3339(),
+            "2026-10-16T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("12h", now).unwrap().to_rfc3339(),
+            "2026-10-17T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2026-10-17T08:00:00+08:00", now)
+                .unwrap()
+                .to_rfc3339(),
+            "2026-10-17T00:00:00+00:00"
+        );
+        assert!(parse_since("yesterday", now).is_err());
+        assert!(parse_since("", now).is_err());
+        assert!(parse_since("3天", now).is_err());
+
+        // Ages that would land in the future or overflow
+        assert!(parse_since("0d", now).is_err());
+        assert!(parse_since("-3d", now).is_err());
+        assert!(parse_since("99999999999999d", now).is_err());
+        assert!(parse_since("99999999w", now).is_err());
+    }
+
+    #[test]
+    fn test_filter_articles() {
+        let articles = vec![
+            article("a", "AI", "TechCrunch", "2026-10-16T08:00:00+00:00"),
+            article("b", "Web", "Hacker News", "2026-10-17T08:00:00+00:00"),
+            article("c", "AI", "Hacker News", "2026-10-17T09:00:00+00:00"),
+        ];
+        let args = ListArgs {
+            tag: vec!["ai".to_string()],
+            ..ListArgs::default()
+        };
+        let since = parse_since("2026-10-17", Utc::now()).ok();
+
+        let titles: Vec<_> = filter_articles(articles, &args, since)
+            .into_iter()
+            .map(|a| a.title)
+            .collect();
+        assert_eq!(titles, vec!["c"]);
+    }
+
+    #[test]
+    fn test_write_plain_aligns_columns() {
+        let articles = vec![
+            article("One", "AI", "量子位", "2026-10-16T08:00:00+00:00"),
+            article("Two", "Web", "HN", "2026-10-16T09:30:00+00:00"),
+        ];
+
+        let mut out = Vec::new();
+        write_list(&mut out, &articles, ListFormat::Plain).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert_eq!(
+            out,
+            "2026-10-16 08:00  AI   量子位  One  https://example.com/One\n\
+             2026-10-16 09:30  Web  HN      Two  https://example.com/Two\n"
+        );
+    }
+
+    #[test]
+    fn test_write_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "limit = 20\n[filters]\ntags = [\"AI\"]\n[keybindings]\nrefresh = \"R\"\n",
+        )
+        .unwrap();
+        let path = path.to_string_lossy().into_owned();
+        let config = Config::load_from([
+            "ptlk",
+            "config",
+            "show",
+            "--config",
+            &path,
+            "--api-url",
+            "https://flag.example",
+        ])
+        .unwrap();
+
+        let mut out = Vec::new();
+        write_config(&mut out, &config).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.starts_with(&format!("# Config file: {}\n", path)));
+        let line = |key: &str| {
+            out.lines()
+                .find(|line| line.starts_with(&format!("{} = ", key)))
+                .unwrap()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        assert_eq!(line("api_url"), "api_url = \"https://flag.example\" # flag");
+        assert_eq!(line("limit"), "limit = 20 # file");
+        assert_eq!(line("tags"), "tags = [\"AI\"] # file");
+        assert_eq!(line("refresh"), "refresh = \"R\" # file");
+        assert_eq!(line("quit"), "quit = \"q\" # default");
+
+        // The output is itself a valid config file
+        let toml: String = out.lines().map(|line| format!("{}\n", line)).collect();
+        assert!(crate::config::FileConfig::parse(&toml).is_ok());
+    }
+
+    #[test]
+    fn test_write_ndjson() {
+        let articles = vec![
+            article("One", "AI", "HN", "2026-10-16T08:00:00+00:00"),
+            article("Two", "AI", "HN", "2026-10-16T09:00:00+00:00"),
+        ];
+
+        let mut out = Vec::new();
+        write_list(&mut out, &articles, ListFormat::Ndjson).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["title"], "One");
+        assert_eq!(first["publishedAt"], "2026-10-16T08:00:00+00:00");
+    }
+}
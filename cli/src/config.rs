@@ -1,4 +1,19 @@
-use clap::Parser;
+use crate::api::{self, Article, DateRange, RetryPolicy};
+use crate::keymap::Keymap;
+use crate::theme::ThemeName;
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Args, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const CONFIG_FILE: &str = "config.toml";
 
 /// Potluck TUI - AI-powered tech news reader
 #[derive(Parser, Debug, Clone)]
@@ -6,7 +21,7 @@ use clap::Parser;
 #[command(version, about, long_about = None)]
 pub struct Config {
     /// API base URL
-    #[arg(long, env = "POTLUCK_API_URL")]
+    #[arg(global = true, long, env = "POTLUCK_API_URL")]
     #[cfg_attr(debug_assertions, arg(default_value = "http://localhost:3000"))]
     #[cfg_attr(
         not(debug_assertions),
@@ -15,22 +30,425 @@ pub struct Config {
     pub api_url: String,
 
     /// Number of articles to fetch per page
-    #[arg(short, long, default_value = "50")]
+    #[arg(global = true, short, long, default_value = "50")]
     pub limit: u32,
 
-    /// Enable debug mode
-    #[arg(short, long, default_value = "false")]
+    /// Start in the Timeline view (all articles, loaded page by page)
+    #[arg(global = true, short, long, default_value = "false")]
+    pub timeline: bool,
+
+    /// Show articles briefed on or after this date (YYYY-MM-DD)
+    #[arg(global = true, long, value_name = "DATE", value_parser = parse_date_arg)]
+    pub from: Option<NaiveDate>,
+
+    /// Show articles briefed on or before this date (YYYY-MM-DD)
+    #[arg(global = true, long, value_name = "DATE", value_parser = parse_date_arg)]
+    pub to: Option<NaiveDate>,
+
+    /// How many times to retry a failed request (0 disables retrying)
+    #[arg(global = true, long, env = "POTLUCK_RETRIES", default_value = "3")]
+    pub retries: u32,
+
+    /// Check for new articles every SECS seconds (0 disables)
+    #[arg(
+        long,
+        env = "POTLUCK_REFRESH_INTERVAL",
+        value_name = "SECS",
+        default_value = "0"
+    )]
+    pub refresh_interval: u64,
+
+    /// Color scheme
+    #[arg(
+        global = true,
+        long,
+        env = "POTLUCK_THEME",
+        value_enum,
+        default_value_t = ThemeName::Dark
+    )]
+    pub theme: ThemeName,
+
+    /// Read settings from FILE instead of ~/.config/ptlk/config.toml
+    #[arg(global = true, long, env = "POTLUCK_CONFIG", value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Don't touch the network; show articles from the local cache only
+    #[arg(global = true, long, default_value = "false")]
+    pub offline: bool,
+
+    /// Write structured debug logs (requests, responses, key events) to --log-file
+    #[arg(global = true, short, long, default_value = "false")]
     pub debug: bool,
+
+    /// Debug log location [default: ~/.cache/ptlk/debug.log]
+    #[arg(global = true, long, env = "POTLUCK_LOG_FILE", value_name = "FILE")]
+    pub log_file: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Key bindings, from the `[keybindings]` table of the config file
+    #[arg(skip)]
+    pub keymap: Keymap,
+
+    /// Tag and source filters, from the `[filters]` table of the config file
+    #[arg(skip)]
+    pub filters: Filters,
+
+    /// Config file that was read, if any
+    #[arg(skip)]
+    pub loaded_file: Option<PathBuf>,
+
+    /// Where each configurable value came from, for `ptlk config show`
+    #[arg(skip)]
+    pub provenance: BTreeMap<String, Source>,
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Print articles to stdout instead of starting the TUI
+    List(ListArgs),
+    /// Write a digest of the day's briefing, grouped by tag
+    Digest(DigestArgs),
+    /// Print articles as an Atom or RSS feed
+    Feed(FeedArgs),
+    /// Inspect the configuration
+    #[command(subcommand)]
+    Config(ConfigCommand),
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum ConfigCommand {
+    /// Print the effective configuration and where each value came from
+    Show,
+}
+
+/// Output and filter options for `ptlk list`
+#[derive(Args, Debug, Clone, Default, PartialEq)]
+pub struct ListArgs {
+    /// Output format
+    #[arg(long, value_enum, default_value_t = ListFormat::Plain)]
+    pub format: ListFormat,
+
+    /// Only show articles with this tag (repeatable, case-insensitive)
+    #[arg(long)]
+    pub tag: Vec<String>,
+
+    /// Only show articles from this source (repeatable, case-insensitive)
+    #[arg(long)]
+    pub source: Vec<String>,
+
+    /// Only show articles published at or after this time: YYYY-MM-DD,
+    /// an RFC 3339 timestamp, or a relative age like 12h or 3d
+    #[arg(long, value_name = "WHEN")]
+    pub since: Option<String>,
+}
+
+/// Options for `ptlk digest`
+#[derive(Args, Debug, Clone, Default, PartialEq)]
+pub struct DigestArgs {
+    /// Output format
+    #[arg(long, value_enum, default_value_t = DigestFormat::Markdown)]
+    pub format: DigestFormat,
+
+    /// Briefing date to summarize (YYYY-MM-DD); defaults to the latest articles
+    #[arg(long, value_name = "DATE", value_parser = parse_date_arg)]
+    pub date: Option<NaiveDate>,
+
+    /// Jinja template to render instead of the built-in layout (markdown and html only)
+    #[arg(long, value_name = "FILE")]
+    pub template: Option<PathBuf>,
+
+    /// Sender address for `--format eml`
+    #[arg(
+        long,
+        env = "POTLUCK_MAIL_FROM",
+        value_name = "ADDR",
+        default_value = "ptlk <ptlk@localhost>"
+    )]
+    pub mail_from: String,
+
+    /// Recipient address for `--format eml` (repeatable)
+    #[arg(
+        long,
+        env = "POTLUCK_MAIL_TO",
+        value_name = "ADDR",
+        value_delimiter = ','
+    )]
+    pub mail_to: Vec<String>,
+
+    /// Write to FILE instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+/// Options for `ptlk feed`
+#[derive(Args, Debug, Clone, Default, PartialEq)]
+pub struct FeedArgs {
+    /// Feed format
+    #[arg(long, value_enum, default_value_t = FeedFormat::Atom)]
+    pub format: FeedFormat,
+
+    /// Write to FILE instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// URL the feed is published at, for the Atom feed's self link
+    #[arg(long, env = "POTLUCK_FEED_URL", value_name = "URL")]
+    pub feed_url: Option<String>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FeedFormat {
+    /// Atom 1.0
+    #[default]
+    Atom,
+    /// RSS 2.0
+    Rss,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DigestFormat {
+    /// Markdown document
+    #[default]
+    Markdown,
+    /// Self-contained HTML page
+    Html,
+    /// Multipart MIME message with text and HTML parts
+    Eml,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListFormat {
+    /// Aligned columns for reading
+    #[default]
+    Plain,
+    /// A single JSON array
+    Json,
+    /// One JSON object per line
+    Ndjson,
+}
+
+/// Only show articles matching these; an empty list matches everything.
+/// Matching is case-insensitive.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Filters {
+    pub tags: Vec<String>,
+    pub sources: Vec<String>,
+    pub exclude_tags: Vec<String>,
+    pub exclude_sources: Vec<String>,
+}
+
+impl Filters {
+    pub fn is_empty(&self) -> bool {
+        self == &Filters::default()
+    }
+
+    pub fn matches(&self, article: &Article) -> bool {
+        let contains =
+            |values: &[String], field: &str| values.iter().any(|v| v.eq_ignore_ascii_case(field));
+
+        (self.tags.is_empty() || contains(&self.tags, &article.tag))
+            && (self.sources.is_empty() || contains(&self.sources, &article.source))
+            && !contains(&self.exclude_tags, &article.tag)
+            && !contains(&self.exclude_sources, &article.source)
+    }
+
+    /// Short form for the status line: `tag:AI,Tech -source:Reddit`
+    pub fn label(&self) -> String {
+        [
+            ("tag:", &self.tags),
+            ("source:", &self.sources),
+            ("-tag:", &self.exclude_tags),
+            ("-source:", &self.exclude_sources),
+        ]
+        .into_iter()
+        .filter(|(_, values)| !values.is_empty())
+        .map(|(prefix, values)| format!("{}{}", prefix, values.join(",")))
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+}
+
+/// The config file's contents. Every key is optional.
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FileConfig {
+    pub api_url: Option<String>,
+    pub limit: Option<u32>,
+    pub refresh_interval: Option<u64>,
+    pub theme: Option<ThemeName>,
+    #[serde(default)]
+    pub keybindings: BTreeMap<String, String>,
+    pub filters: Option<Filters>,
+}
+
+impl FileConfig {
+    pub fn parse(toml: &str) -> Result<Self> {
+        Ok(toml::from_str(toml)?)
+    }
+}
+
+/// Which layer a setting came from. Later layers win: file, then env, then flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File,
+    Env(&'static str),
+    Flag,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File => write!(f, "file"),
+            Source::Env(var) => write!(f, "env {}", var),
+            Source::Flag => write!(f, "flag"),
+        }
+    }
+}
+
+impl Config {
+    /// Parse the command line and layer it over the environment and the config file
+    pub fn load() -> Result<Self> {
+        Self::load_from(std::env::args_os())
+    }
+
+    pub fn load_from<I, T>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let matches = Self::command().get_matches_from(args);
+        let mut config = Self::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+        // An explicit --config must exist; the default location is optional
+        let (path, required) = match &config.config {
+            Some(path) => (Some(path.clone()), true),
+            None => (Self::default_config_path(), false),
+        };
+        let file = match path {
+            Some(path) if required || path.exists() => {
+                let toml = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                let file = FileConfig::parse(&toml)
+                    .with_context(|| format!("Invalid config file {}", path.display()))?;
+                config.loaded_file = Some(path);
+                file
+            }
+            _ => FileConfig::default(),
+        };
+
+        config
+            .merge(file, &matches)
+            .with_context(|| match &config.loaded_file {
+                Some(path) => format!("Invalid config file {}", path.display()),
+                None => "Invalid configuration".to_string(),
+            })?;
+        Ok(config)
+    }
+
+    /// `$XDG_CONFIG_HOME/ptlk/config.toml` (or the platform equivalent)
+    pub fn default_config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("ptlk").join(CONFIG_FILE))
+    }
+
+    /// Fill in values that weren't given as a flag or env var from `file`,
+    /// recording where each one came from
+    fn merge(&mut self, file: FileConfig, matches: &ArgMatches) -> Result<()> {
+        let source = |id: &str, env: Option<&'static str>| match (matches.value_source(id), env) {
+            (Some(ValueSource::CommandLine), _) => Source::Flag,
+            (Some(ValueSource::EnvVariable), Some(env)) => Source::Env(env),
+            _ => Source::Default,
+        };
+
+        let api_url = layer(
+            &mut self.api_url,
+            file.api_url,
+            source("api_url", Some("POTLUCK_API_URL")),
+        );
+        let limit = layer(&mut self.limit, file.limit, source("limit", None));
+        let refresh_interval = layer(
+            &mut self.refresh_interval,
+            file.refresh_interval,
+            source("refresh_interval", Some("POTLUCK_REFRESH_INTERVAL")),
+        );
+        let theme = layer(
+            &mut self.theme,
+            file.theme,
+            source("theme", Some("POTLUCK_THEME")),
+        );
+        let filters = layer(&mut self.filters, file.filters, Source::Default);
+        self.keymap = Keymap::with_overrides(&file.keybindings)?;
+
+        self.provenance = BTreeMap::from([
+            ("api_url".to_string(), api_url),
+            ("limit".to_string(), limit),
+            ("refresh_interval".to_string(), refresh_interval),
+            ("theme".to_string(), theme),
+            ("filters".to_string(), filters),
+        ]);
+        for (action, _) in self.keymap.bindings() {
+            let source = if file.keybindings.contains_key(action.name()) {
+                Source::File
+            } else {
+                Source::Default
+            };
+            self.provenance
+                .insert(format!("keybindings.{}", action.name()), source);
+        }
+        Ok(())
+    }
+
+    /// Where `key` came from; see [`Config::provenance`]
+    pub fn source(&self, key: &str) -> Source {
+        self.provenance.get(key).copied().unwrap_or(Source::Default)
+    }
+
+    /// Auto-refresh period, `None` when disabled
+    pub fn refresh_interval(&self) -> Option<Duration> {
+        (self.refresh_interval > 0).then(|| Duration::from_secs(self.refresh_interval))
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_retries: self.retries,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// The date range requested with `--from`/`--to`, if any
+    pub fn date_range(&self) -> Result<Option<DateRange>> {
+        DateRange::new(self.from, self.to)
+    }
+}
+
+/// Replace `value` with the file's unless a flag or env var already set it
+fn layer<T>(value: &mut T, file: Option<T>, source: Source) -> Source {
+    match (source, file) {
+        (Source::Default, Some(file)) => {
+            *value = file;
+            Source::File
+        }
+        (source, _) => source,
+    }
+}
+
+fn parse_date_arg(value: &str) -> Result<NaiveDate, String> {
+    api::parse_date(value).map_err(|e| e.to_string())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support;
 
     #[test]
     fn test_config_defaults() {
         // Simulate parsing with no arguments
-        let config = Config::parse_from(&["ptlk"]);
+        let config = Config::parse_from(["ptlk"]);
 
         let expected_url = if cfg!(debug_assertions) {
             "http://localhost:3000"
@@ -40,6 +458,181 @@ mod tests {
 
         assert_eq!(config.api_url, expected_url);
         assert_eq!(config.limit, 50); // Expect 50
-        assert_eq!(config.debug, false);
+        assert!(!config.debug);
+        assert!(!config.timeline);
+        assert_eq!(config.retries, 3);
+        assert!(!config.offline);
+        assert!(config.refresh_interval().is_none());
+        assert!(config.command.is_none());
+        assert!(config.date_range().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_list_subcommand() {
+        let config = Config::parse_from([
+            "ptlk", "list", "--format", "ndjson", "--tag", "AI", "--tag", "Web", "--limit", "10",
+        ]);
+
+        assert_eq!(config.limit, 10); // Global flags work after the subcommand
+        assert_eq!(
+            config.command,
+            Some(Command::List(ListArgs {
+                format: ListFormat::Ndjson,
+                tag: vec!["AI".to_string(), "Web".to_string()],
+                source: vec![],
+                since: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_digest_subcommand() {
+        let config = Config::parse_from(["ptlk", "digest", "--date", "2026-10-17", "-o", "out.md"]);
+
+        assert_eq!(
+            config.command,
+            Some(Command::Digest(DigestArgs {
+                format: DigestFormat::Markdown,
+                date: NaiveDate::from_ymd_opt(2026, 10, 17),
+                template: None,
+                output: Some(PathBuf::from("out.md")),
+                mail_from: "ptlk <ptlk@localhost>".to_string(),
+                mail_to: vec![],
+            }))
+        );
+
+        let config = Config::parse_from([
+            "ptlk",
+            "digest",
+            "--format",
+            "eml",
+            "--mail-to",
+            "a@example.com,b@example.com",
+        ]);
+        let Some(Command::Digest(args)) = config.command else {
+            panic!("expected digest");
+        };
+        assert_eq!(args.format, DigestFormat::Eml);
+        assert_eq!(args.mail_to, vec!["a@example.com", "b@example.com"]);
+        assert!(Config::try_parse_from(["ptlk", "digest", "--date", "today"]).is_err());
+    }
+
+    #[test]
+    fn test_feed_subcommand() {
+        let config = Config::parse_from([
+            "ptlk",
+            "feed",
+            "--format",
+            "rss",
+            "--offline",
+            "--feed-url",
+            "https://example.com/potluck.atom",
+        ]);
+
+        assert!(config.offline);
+        assert_eq!(
+            config.command,
+            Some(Command::Feed(FeedArgs {
+                format: FeedFormat::Rss,
+                output: None,
+                feed_url: Some("https://example.com/potluck.atom".to_string()),
+            }))
+        );
+    }
+
+    fn write_config(toml: &str) -> (tempfile::TempDir, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONFIG_FILE);
+        fs::write(&path, toml).unwrap();
+        let path = path.to_string_lossy().into_owned();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_config_file_layering() {
+        let (_dir, path) = write_config(
+            r#"
+            api_url = "https://file.example"
+            limit = 20
+            theme = "light"
+
+            [filters]
+            exclude_tags = ["Crypto"]
+
+            [keybindings]
+            down = "e"
+            "#,
+        );
+
+        let config =
+            Config::load_from(["ptlk", "list", "--limit", "10", "--config", &path]).unwrap();
+
+        assert_eq!(config.api_url, "https://file.example");
+        assert_eq!(config.source("api_url"), Source::File);
+        assert_eq!(config.limit, 10);
+        assert_eq!(config.source("limit"), Source::Flag);
+        assert_eq!(config.theme, ThemeName::Light);
+        assert_eq!(config.refresh_interval, 0);
+        assert_eq!(config.source("refresh_interval"), Source::Default);
+        assert_eq!(config.filters.exclude_tags, vec!["Crypto"]);
+        assert_eq!(config.source("filters"), Source::File);
+        assert_eq!(
+            config.keymap.key(crate::keymap::Action::Down).to_string(),
+            "e"
+        );
+        assert_eq!(config.source("keybindings.down"), Source::File);
+        assert_eq!(config.source("keybindings.up"), Source::Default);
+        assert_eq!(
+            config.loaded_file.as_deref(),
+            Some(std::path::Path::new(&path))
+        );
+    }
+
+    #[test]
+    fn test_config_file_errors() {
+        let (_dir, path) = write_config("colour = \"blue\"\n");
+        let err = Config::load_from(["ptlk", "--config", &path]).unwrap_err();
+        assert!(format!("{:#}", err).contains("unknown field `colour`"));
+
+        let (_dir, path) = write_config("[keybindings]\nquit = \"j\"\n");
+        let err = Config::load_from(["ptlk", "--config", &path]).unwrap_err();
+        assert!(format!("{:#}", err).contains("bound to both"));
+
+        assert!(Config::load_from(["ptlk", "--config", "/nonexistent/ptlk.toml"]).is_err());
+    }
+
+    #[test]
+    fn test_filters_match() {
+        let article = Article {
+            tag: "AI".to_string(),
+            source: "Hacker News".to_string(),
+            ..test_support::article("https://example.com")
+        };
+
+        assert!(Filters::default().matches(&article));
+        let only_ai = Filters {
+            tags: vec!["ai".to_string()],
+            ..Filters::default()
+        };
+        assert!(only_ai.matches(&article));
+        let no_hn = Filters {
+            exclude_sources: vec!["hacker news".to_string()],
+            ..Filters::default()
+        };
+        assert!(!no_hn.matches(&article));
+        assert_eq!(no_hn.label(), "-source:hacker news");
+        assert_eq!(only_ai.label(), "tag:ai");
+    }
+
+    #[test]
+    fn test_config_date_range() {
+        let config = Config::parse_from(["ptlk", "--from", "2026-10-01", "--to", "2026-10-05"]);
+        let range = config.date_range().unwrap().unwrap();
+        assert_eq!(range.label(), "2026-10-01..2026-10-05");
+
+        let config = Config::parse_from(["ptlk", "--from", "2026-10-05", "--to", "2026-10-01"]);
+        assert!(config.date_range().is_err());
+
+        assert!(Config::try_parse_from(["ptlk", "--from", "10/05/2026"]).is_err());
     }
 }
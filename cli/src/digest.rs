@@ -0,0 +1,455 @@
+use crate::api::Article;
+use crate::commands::{self, write_stdout};
+use crate::config::{Config, DigestArgs, DigestFormat};
+use crate::email::Message;
+use anyhow::{bail, Context, Result};
+use chrono::{NaiveDate, Utc};
+use minijinja::{Environment, Value};
+use pulldown_cmark::{html, Event, Options, Parser, Tag, TagEnd};
+use serde::Serialize;
+use std::fs;
+
+/// Built-in Markdown layout; `--template` replaces it
+pub const DEFAULT_MARKDOWN_TEMPLATE: &str = r#"# Potluck Daily Briefing — {{ date }}
+
+{{ total }} article{% if total != 1 %}s{% endif %} across {{ groups | length }} topic{% if groups | length != 1 %}s{% endif %}.
+{% for group in groups %}
+
+## {{ group.tag }}
+{% for article in group.articles %}
+
+{% if article.url is http_url %}
+### [{{ article.title | escape_md }}]({{ article.url }})
+{% else %}
+### {{ article.title }}
+{% endif %}
+
+*{{ article.source }}*
+{% if article.summary %}
+
+{{ article.summary }}
+{% endif %}
+{% endfor %}
+{% endfor %}
+"#;
+
+/// Built-in HTML layout: a single page with inline CSS and no external assets
+pub const DEFAULT_HTML_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Potluck Daily Briefing — {{ date }}</title>
+<style>
+  body { margin: 0; padding: 24px 16px; background: #f6f6f4; color: #1f2328; font: 16px/1.55 -apple-system, BlinkMacSystemFont, "Segoe UI", "PingFang SC", "Noto Sans CJK SC", sans-serif; }
+  main { max-width: 720px; margin: 0 auto; background: #fff; border-radius: 8px; padding: 24px 32px; }
+  h1 { font-size: 24px; margin: 0 0 4px; }
+  .meta { color: #6e7781; font-size: 14px; margin: 0 0 8px; }
+  h2 { font-size: 18px; color: #0969da; border-bottom: 1px solid #d0d7de; padding-bottom: 4px; margin: 28px 0 8px; }
+  article { margin: 16px 0; }
+  h3 { font-size: 16px; margin: 0; }
+  h3 a { color: #1f2328; text-decoration: none; }
+  h3 a:hover { text-decoration: underline; }
+  .source { color: #6e7781; font-size: 13px; margin: 2px 0 6px; }
+  .summary p, .summary ul, .summary ol { margin: 0 0 6px; }
+  .summary ul, .summary ol { padding-left: 20px; }
+  .summary code { font-size: 14px; background: #f6f8fa; border-radius: 4px; padding: 1px 4px; }
+</style>
+</head>
+<body>
+<main>
+<h1>Potluck Daily Briefing — {{ date }}</h1>
+<p class="meta">{{ total }} article{% if total != 1 %}s{% endif %} across {{ groups | length }} topic{% if groups | length != 1 %}s{% endif %}</p>
+{% for group in groups %}
+<h2>{{ group.tag }}</h2>
+{% for article in group.articles %}
+<article>
+  {% if article.url is http_url %}
+  <h3><a href="{{ article.url }}">{{ article.title }}</a></h3>
+  {% else %}
+  <h3>{{ article.title }}</h3>
+  {% endif %}
+  <p class="source">{{ article.source }}</p>
+  {% if article.summary %}
+  <div class="summary">{{ article.summary | markdown }}</div>
+  {% endif %}
+</article>
+{% endfor %}
+{% endfor %}
+</main>
+</body>
+</html>
+"#;
+
+/// Articles sharing a tag
+#[derive(Debug, Serialize)]
+pub struct TagGroup<'a> {
+    pub tag: &'a str,
+    pub articles: Vec<&'a Article>,
+}
+
+/// Everything a digest template can use
+#[derive(Debug, Serialize)]
+pub struct Digest<'a> {
+    /// Briefing date (YYYY-MM-DD)
+    pub date: String,
+    pub generated_at: String,
+    pub total: usize,
+    pub groups: Vec<TagGroup<'a>>,
+    pub articles: &'a [Article],
+}
+
+impl<'a> Digest<'a> {
+    pub fn new(date: NaiveDate, articles: &'a [Article]) -> Self {
+        Self {
+            date: date.to_string(),
+            generated_at: Utc::now().to_rfc3339(),
+            total: articles.len(),
+            groups: group_by_tag(articles),
+            articles,
+        }
+    }
+}
+
+/// Group articles by tag, biggest group first (ties by name), keeping the
+/// original order inside each group
+pub fn group_by_tag(articles: &[Article]) -> Vec<TagGroup<'_>> {
+    let mut groups: Vec<TagGroup> = Vec::new();
+    for article in articles {
+        match groups.iter_mut().find(|g| g.tag == article.tag) {
+            Some(group) => group.articles.push(article),
+            None => groups.push(TagGroup {
+                tag: &article.tag,
+                articles: vec![article],
+            }),
+        }
+    }
+
+    groups.sort_by(|a, b| {
+        b.articles
+            .len()
+            .cmp(&a.articles.len())
+            .then_with(|| a.tag.cmp(b.tag))
+    });
+    groups
+}
+
+/// The most recent briefing date among `articles`, if any has a valid one
+pub fn newest_briefing_date(articles: &[Article]) -> Option<NaiveDate> {
+    articles
+        .iter()
+        .filter_map(|a| NaiveDate::parse_from_str(&a.briefing_date, "%Y-%m-%d").ok())
+        .max()
+}
+
+/// Render `digest` with a Jinja-style template. HTML output is auto-escaped.
+pub fn render_template(template: &str, format: DigestFormat, digest: &Digest) -> Result<String> {
+    // minijinja picks auto-escaping from the template name
+    let name = match format {
+        DigestFormat::Markdown | DigestFormat::Eml => "digest.md",
+        DigestFormat::Html => "digest.html",
+    };
+
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_keep_trailing_newline(true);
+    env.add_filter("escape_md", escape_markdown);
+    env.add_filter("markdown", |text: String| {
+        Value::from_safe_string(markdown_to_html(&text))
+    });
+    env.add_test("http_url", |url: String| is_http_url(&url));
+    env.add_template(name, template)
+        .context("Invalid digest template")?;
+
+    let rendered = env
+        .get_template(name)?
+        .render(digest)
+        .context("Failed to render digest template")?;
+    Ok(rendered)
+}
+
+/// Backslash-escape characters that would break inline Markdown such as link text
+fn escape_markdown(text: String) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '\\' | '[' | ']' | '*' | '_' | '`') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// Whether `url` is safe to link to: plain http(s), no `javascript:` and the like
+fn is_http_url(url: &str) -> bool {
+    let url = url.trim_start().to_ascii_lowercase();
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+/// Render a Markdown summary to HTML. Raw HTML is escaped, links to anything
+/// but http(s) keep only their text, and images are dropped to their alt text.
+/// Single line breaks are kept, as in the TUI.
+fn markdown_to_html(text: &str) -> String {
+    let mut dropped_links = 0;
+    let events = Parser::new_ext(text, Options::ENABLE_STRIKETHROUGH).filter_map(|event| {
+        Some(match event {
+            Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+            Event::SoftBreak => Event::HardBreak,
+            Event::Start(Tag::Link { ref dest_url, .. }) if !is_http_url(dest_url) => {
+                dropped_links += 1;
+                return None;
+            }
+            Event::Start(Tag::Image { .. }) => {
+                dropped_links += 1;
+                return None;
+            }
+            Event::End(TagEnd::Link | TagEnd::Image) if dropped_links > 0 => {
+                dropped_links -= 1;
+                return None;
+            }
+            event => event,
+        })
+    });
+
+    let mut out = String::new();
+    html::push_html(&mut out, events);
+    out
+}
+
+/// A ready-to-send email with the Markdown digest as the text part and the
+/// HTML digest as the alternative
+pub fn render_email(digest: &Digest, from: &str, to: &[String]) -> Result<String> {
+    if to.is_empty() {
+        bail!("--format eml needs a recipient: pass --mail-to or set POTLUCK_MAIL_TO");
+    }
+    let message = Message {
+        from: from.to_string(),
+        to: to.to_vec(),
+        subject: format!("Potluck Daily Briefing — {}", digest.date),
+        date: Utc::now(),
+        text: render_template(DEFAULT_MARKDOWN_TEMPLATE, DigestFormat::Markdown, digest)?,
+        html: render_template(DEFAULT_HTML_TEMPLATE, DigestFormat::Html, digest)?,
+    };
+    message.to_mime()
+}
+
+/// `ptlk digest`: write a briefing for today or `--date`
+pub async fn run(config: &Config, args: &DigestArgs) -> Result<()> {
+    let mut config = config.clone();
+    if let Some(date) = args.date {
+        config.from = Some(date);
+        config.to = Some(date);
+    }
+
+    let articles = commands::load_articles(&config).await?;
+    // `/api/latest` spans two briefings; head the digest with the newer one
+    let date = args
+        .date
+        .or_else(|| newest_briefing_date(&articles))
+        .unwrap_or_else(|| Utc::now().date_naive());
+    let digest = Digest::new(date, &articles);
+
+    let output = match (args.format, &args.template) {
+        (DigestFormat::Eml, Some(_)) => bail!("--template can't be used with --format eml"),
+        (DigestFormat::Eml, None) => render_email(&digest, &args.mail_from, &args.mail_to)?,
+        (format, Some(path)) => {
+            let template = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read template {}", path.display()))?;
+            render_template(&template, format, &digest)?
+        }
+        (DigestFormat::Markdown, None) => {
+            render_template(DEFAULT_MARKDOWN_TEMPLATE, DigestFormat::Markdown, &digest)?
+        }
+        (DigestFormat::Html, None) => {
+            render_template(DEFAULT_HTML_TEMPLATE, DigestFormat::Html, &digest)?
+        }
+    };
+
+    match &args.output {
+        Some(path) => {
+            fs::write(path, output).with_context(|| format!("Failed to write {}", path.display()))
+        }
+        None => write_stdout(|out| out.write_all(output.as_bytes())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    fn article(title: &str, tag: &str, summary: &str) -> Article {
+        Article {
+            title: title.to_string(),
+            summary: summary.to_string(),
+            tag: tag.to_string(),
+            source: "HN".to_string(),
+            published_at: "2026-10-16T08:00:00+00:00".to_string(),
+            briefing_date: "2026-10-17".to_string(),
+            ..test_support::article(&format!("https://example.com/{}", title.len()))
+        }
+    }
+
+    fn sample() -> Vec<Article> {
+        vec![
+            article("Rust 2.0", "Dev", "- **fast**\n- safe"),
+            article("[Draft] GPT", "AI", "New model."),
+            article("Claude", "AI", ""),
+        ]
+    }
+
+    #[test]
+    fn test_group_by_tag() {
+        let articles = sample();
+        let groups = group_by_tag(&articles);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].tag, "AI");
+        assert_eq!(groups[0].articles.len(), 2);
+        assert_eq!(groups[0].articles[0].title, "[Draft] GPT");
+        assert_eq!(groups[1].tag, "Dev");
+    }
+
+    #[test]
+    fn test_newest_briefing_date() {
+        let mut articles = sample();
+        articles[1].briefing_date = "2026-10-16".to_string();
+        articles[2].briefing_date = "not a date".to_string();
+        assert_eq!(
+            newest_briefing_date(&articles),
+            NaiveDate::from_ymd_opt(2026, 10, 17)
+        );
+        assert_eq!(newest_briefing_date(&[]), None);
+    }
+
+    #[test]
+    fn test_default_template() {
+        let articles = sample();
+        let date = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let output = render_template(
+            DEFAULT_MARKDOWN_TEMPLATE,
+            DigestFormat::Markdown,
+            &Digest::new(date, &articles),
+        )
+        .unwrap();
+
+        assert_eq!(
+            output,
+            "# Potluck Daily Briefing — 2026-10-17\n\
+             \n\
+             3 articles across 2 topics.\n\
+             \n\
+             ## AI\n\
+             \n\
+             ### [\\[Draft\\] GPT](https://example.com/11)\n\
+             \n\
+             *HN*\n\
+             \n\
+             New model.\n\
+             \n\
+             ### [Claude](https://example.com/6)\n\
+             \n\
+             *HN*\n\
+             \n\
+             ## Dev\n\
+             \n\
+             ### [Rust 2.0](https://example.com/8)\n\
+             \n\
+             *HN*\n\
+             \n\
+             - **fast**\n\
+             - safe\n"
+        );
+    }
+
+    #[test]
+    fn test_custom_template() {
+        let articles = sample();
+        let date = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let template = "{% for a in articles %}- {{ a.title }} ({{ a.tag }})\n{% endfor %}";
+
+        let output = render_template(
+            template,
+            DigestFormat::Markdown,
+            &Digest::new(date, &articles),
+        )
+        .unwrap();
+        assert_eq!(
+            output,
+            "- Rust 2.0 (Dev)\n- [Draft] GPT (AI)\n- Claude (AI)\n"
+        );
+    }
+
+    #[test]
+    fn test_html_template_escapes() {
+        let mut articles = sample();
+        articles[0].title = "<script>alert(1)</script>".to_string();
+        let date = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+
+        let output = render_template(
+            DEFAULT_HTML_TEMPLATE,
+            DigestFormat::Html,
+            &Digest::new(date, &articles),
+        )
+        .unwrap();
+
+        assert!(output.starts_with("<!DOCTYPE html>"));
+        assert!(output.contains("<style>"));
+        assert!(!output.contains("<link") && !output.contains("<script>"));
+        assert!(output.contains("&lt;script&gt;alert(1)&lt;&#x2f;script&gt;"));
+        assert!(
+            output.contains("<a href=\"https:&#x2f;&#x2f;example.com&#x2f;11\">[Draft] GPT</a>")
+        );
+        assert!(output.contains("<h2>AI</h2>"));
+    }
+
+    #[test]
+    fn test_render_email() {
+        let articles = sample();
+        let date = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let to = vec!["me@example.com".to_string()];
+
+        let mime =
+            render_email(&Digest::new(date, &articles), "ptlk <ptlk@localhost>", &to).unwrap();
+
+        assert!(mime.contains("To: me@example.com\r\n"));
+        assert!(mime.contains("Content-Type: text/plain; charset=utf-8\r\n"));
+        assert!(mime.contains("Content-Type: text/html; charset=utf-8\r\n"));
+
+        let err =
+            render_email(&Digest::new(date, &articles), "ptlk <ptlk@localhost>", &[]).unwrap_err();
+        assert!(err.to_string().contains("--mail-to"));
+    }
+
+    #[test]
+    fn test_html_summary_is_rendered_markdown() {
+        let mut articles = sample();
+        articles[1].summary =
+            "*New* <script>x</script>\n[docs](https://example.com/d) [bad](javascript:alert(1))"
+                .to_string();
+        articles[2].url = "javascript:alert(1)".to_string();
+        let date = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+
+        let output = render_template(
+            DEFAULT_HTML_TEMPLATE,
+            DigestFormat::Html,
+            &Digest::new(date, &articles),
+        )
+        .unwrap();
+
+        assert!(output.contains("<ul>\n<li><strong>fast</strong></li>\n<li>safe</li>\n</ul>"));
+        assert!(output.contains("<em>New</em> &lt;script&gt;x&lt;/script&gt;<br />"));
+        assert!(output.contains("<a href=\"https://example.com/d\">docs</a> bad"));
+        assert!(!output.contains("javascript:"));
+        assert!(output.contains("<h3>Claude</h3>"));
+    }
+
+    #[test]
+    fn test_invalid_template() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        assert!(
+            render_template("{% for %}", DigestFormat::Markdown, &Digest::new(date, &[])).is_err()
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use anyhow::{bail, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+
+/// Maximum encoded line length allowed by RFC 2045
+const MAX_LINE: usize = 76;
+
+/// Longest UTF-8 run per encoded-word: 45 bytes make 60 base64 characters,
+/// which with `=?UTF-8?B?` and `?=` stays within RFC 2047's 75
+const MAX_ENCODED_WORD_BYTES: usize = 45;
+
+/// Characters that can't appear unquoted in a display name (RFC 5322 `specials`)
+const SPECIALS: &[char] = &[
+    '(', ')', '<', '>', '[', ']', ':', ';', '@', '\\', ',', '.', '"',
+];
+
+/// A `multipart/alternative` message with a plain-text and an HTML body
+#[derive(Debug, Clone)]
+pub struct Message {
+    pub from: String,
+    pub to: Vec<String>,
+    pub subject: String,
+    pub date: DateTime<Utc>,
+    pub text: String,
+    pub html: String,
+}
+
+impl Message {
+    /// Serialize as an RFC 5322 message with CRLF line endings, ready for
+    /// `sendmail -t`. Addresses with line breaks, which would start headers of
+    /// their own, are an error.
+    pub fn to_mime(&self) -> Result<String> {
+        for mailbox in std::iter::once(&self.from).chain(&self.to) {
+            if mailbox.contains(['\r', '\n']) {
+                bail!("Invalid email address {:?}: contains a line break", mailbox);
+            }
+        }
+
+        let token = format!("{:016x}", fastrand::u64(..));
+        let boundary = format!("ptlk-{}", token);
+        let domain = self
+            .from
+            .rsplit_once('@')
+            .map(|(_, domain)| domain.trim_end_matches('>'))
+            .unwrap_or("localhost");
+
+        let to: Vec<String> = self.to.iter().map(|addr| encode_mailbox(addr)).collect();
+
+        let mut out = String::new();
+        header(&mut out, "From", &encode_mailbox(&self.from));
+        header(&mut out, "To", &to.join(", "));
+        header(&mut out, "Subject", &encode_header(&self.subject));
+        header(&mut out, "Date", &self.date.to_rfc2822());
+        header(
+            &mut out,
+            "Message-ID",
+            &format!("<{}.{}@{}>", self.date.timestamp(), token, domain),
+        );
+        header(&mut out, "MIME-Version", "1.0");
+        header(
+            &mut out,
+            "Content-Type",
+            &format!("multipart/alternative; boundary=\"{}\"", boundary),
+        );
+        out.push_str("\r\n");
+
+        for (content_type, body) in [("text/plain", &self.text), ("text/html", &self.html)] {
+            out.push_str(&format!("--{}\r\n", boundary));
+            header(
+                &mut out,
+                "Content-Type",
+                &format!("{}; charset=utf-8", content_type),
+            );
+            header(&mut out, "Content-Transfer-Encoding", "base64");
+            out.push_str("\r\n");
+            out.push_str(&encode_body(body));
+        }
+        out.push_str(&format!("--{}--\r\n", boundary));
+        Ok(out)
+    }
+}
+
+fn header(out: &mut String, name: &str, value: &str) {
+    out.push_str(name);
+    out.push_str(": ");
+    out.push_str(value);
+    out.push_str("\r\n");
+}
+
+/// RFC 2047 encoded-words for non-ASCII header values, folded onto
+/// continuation lines so none is longer than 75 characters
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        return value.to_string();
+    }
+
+    let mut words = vec![];
+    let mut start = 0;
+    for (i, ch) in value.char_indices() {
+        if i + ch.len_utf8() - start > MAX_ENCODED_WORD_BYTES {
+            words.push(&value[start..i]);
+            start = i;
+        }
+    }
+    words.push(&value[start..]);
+
+    words
+        .iter()
+        .map(|word| format!("=?UTF-8?B?{}?=", STANDARD.encode(word)))
+        .collect::<Vec<_>>()
+        .join("\r\n ")
+}
+
+/// `Name <addr>` with a non-ASCII display name encoded per RFC 2047 and an
+/// ASCII one quoted if it has specials; the address itself is left alone
+fn encode_mailbox(mailbox: &str) -> String {
+    match mailbox.rsplit_once('<') {
+        Some((name, addr)) if !name.trim().is_empty() => {
+            format!("{} <{}", encode_display_name(name.trim()), addr)
+        }
+        _ => mailbox.to_string(),
+    }
+}
+
+fn encode_display_name(name: &str) -> String {
+    let quoted = name.len() >= 2 && name.starts_with('"') && name.ends_with('"');
+    if !name.is_ascii() || quoted || !name.contains(SPECIALS) {
+        return encode_header(name);
+    }
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Base64 body wrapped at [`MAX_LINE`] columns
+fn encode_body(body: &str) -> String {
+    let encoded = STANDARD.encode(body);
+    let mut out = String::with_capacity(encoded.len() + encoded.len() / MAX_LINE * 2 + 2);
+    for line in encoded.as_bytes().chunks(MAX_LINE) {
+        out.push_str(std::str::from_utf8(line).expect("base64 is ASCII"));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message() -> Message {
+        Message {
+            from: "ptlk <digest@example.com>".to_string(),
+            to: vec!["a@example.com".to_string(), "b@example.com".to_string()],
+            subject: "Potluck Daily Briefing — 2026-10-17".to_string(),
+            date: DateTime::parse_from_rfc3339("2026-10-17T08:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            text: "Hello 世界".to_string(),
+            html: "<p>Hello 世界</p>".repeat(10),
+        }
+    }
+
+    #[test]
+    fn test_mime_headers() {
+        let mime = message().to_mime().unwrap();
+        let (headers, _) = mime.split_once("\r\n\r\n").unwrap();
+
+        assert!(headers.contains("From: ptlk <digest@example.com>\r\n"));
+        assert!(headers.contains("To: a@example.com, b@example.com\r\n"));
+        assert!(headers.contains("Subject: =?UTF-8?B?"));
+        assert!(headers.contains("Date: Sat, 17 Oct 2026 08:00:00 +0000\r\n"));
+        assert!(headers.contains("@example.com>\r\n"));
+        assert!(headers.contains("MIME-Version: 1.0\r\n"));
+        assert!(headers.contains("Content-Type: multipart/alternative; boundary=\"ptlk-"));
+    }
+
+    #[test]
+    fn test_mime_encodes_display_names() {
+        let message = Message {
+            from: "土豆 <digest@example.com>".to_string(),
+            to: vec!["Zoë <zoe@example.com>".to_string()],
+            ..message()
+        };
+        let mime = message.to_mime().unwrap();
+        let (headers, _) = mime.split_once("\r\n\r\n").unwrap();
+
+        assert!(headers.contains(&format!(
+            "From: =?UTF-8?B?{}?= <digest@example.com>\r\n",
+            STANDARD.encode("土豆")
+        )));
+        assert!(headers.contains(&format!(
+            "To: =?UTF-8?B?{}?= <zoe@example.com>\r\n",
+            STANDARD.encode("Zoë")
+        )));
+        // Message-ID still uses the sender's domain
+        assert!(headers.contains("@example.com>\r\nMIME-Version"));
+    }
+
+    #[test]
+    fn test_mime_parts_roundtrip() {
+        let message = message();
+        let mime = message.to_mime().unwrap();
+        let boundary = mime
+            .split_once("boundary=\"")
+            .and_then(|(_, rest)| rest.split_once('"'))
+            .map(|(boundary, _)| boundary)
+            .unwrap();
+
+        let parts: Vec<_> = mime.split(&format!("--{}", boundary)).collect();
+        // Preamble, text, html, closing "--"
+        assert_eq!(parts.len(), 4);
+        assert!(parts[3].starts_with("--"));
+
+        for (part, expected) in [(parts[1], &message.text), (parts[2], &message.html)] {
+            let (headers, body) = part.split_once("\r\n\r\n").unwrap();
+            assert!(headers.contains("charset=utf-8"));
+            assert!(body.lines().all(|line| line.len() <= MAX_LINE + 1));
+
+            let encoded: String = body.split_whitespace().collect();
+            let decoded = String::from_utf8(STANDARD.decode(encoded).unwrap()).unwrap();
+            assert_eq!(&decoded, expected);
+        }
+    }
+
+    #[test]
+    fn test_mime_quotes_display_names_with_specials() {
+        let message = Message {
+            from: "Potluck, Inc. <digest@example.com>".to_string(),
+            to: vec![
+                "\"Doe, Jane\" <jane@example.com>".to_string(),
+                "Jo \"JJ\" Smith; Jr <jo@example.com>".to_string(),
+            ],
+            ..message()
+        };
+        let mime = message.to_mime().unwrap();
+
+        assert!(mime.contains("From: \"Potluck, Inc.\" <digest@example.com>\r\n"));
+        assert!(mime.contains(
+            "To: \"Doe, Jane\" <jane@example.com>, \"Jo \\\"JJ\\\" Smith; Jr\" <jo@example.com>\r\n"
+        ));
+    }
+
+    #[test]
+    fn test_mime_rejects_line_breaks_in_addresses() {
+        let injected_to = Message {
+            to: vec!["a@example.com\r\nBcc: victim@example.com".to_string()],
+            ..message()
+        };
+        assert!(injected_to.to_mime().is_err());
+
+        let injected_from = Message {
+            from: "ptlk\n <digest@example.com>".to_string(),
+            ..message()
+        };
+        assert!(injected_from.to_mime().is_err());
+    }
+
+    #[test]
+    fn test_long_header_split_into_encoded_words() {
+        let value = "土豆日报".repeat(10);
+        let encoded = encode_header(&value);
+
+        let words: Vec<&str> = encoded.split("\r\n ").collect();
+        assert!(words.len() > 1);
+        let mut decoded = Vec::new();
+        for word in words {
+            assert!(word.len() <= 75, "{} is too long", word);
+            let base64 = word
+                .strip_prefix("=?UTF-8?B?")
+                .and_then(|w| w.strip_suffix("?="))
+                .unwrap();
+            decoded.extend(STANDARD.decode(base64).unwrap());
+        }
+        // Each word holds whole characters
+        assert_eq!(String::from_utf8(decoded).unwrap(), value);
+    }
+
+    #[test]
+    fn test_ascii_subject_not_encoded() {
+        assert_eq!(encode_header("Daily"), "Daily");
+        assert_eq!(encode_header("日报"), "=?UTF-8?B?5pel5oql?=");
+    }
+}
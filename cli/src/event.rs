@@ -1,65 +1,194 @@
-use crate::app::App;
+use crate::app::{App, Tab};
+use crate::keymap::Action;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Handle key events
 pub fn handle_key_event(app: &mut App, key: KeyEvent) {
-    match key.code {
-        // Quit
-        KeyCode::Char('q') | KeyCode::Esc => {
-            app.quit();
+    // Any key dismisses the error popup, and does nothing else
+    if app.last_error.take().is_some() {
+        return;
+    }
+    if app.date_prompt.is_some() {
+        handle_date_prompt_key(app, key);
+        return;
+    }
+    if app.search_prompt.is_some() {
+        handle_search_prompt_key(app, key);
+        return;
+    }
+    if app.facet_picker.is_some() {
+        handle_facet_picker_key(app, key);
+        return;
+    }
+
+    // Esc clears highlighted search matches before it quits
+    if key.code == KeyCode::Esc && !app.search.is_empty() {
+        app.clear_search();
+        return;
+    }
+
+    // Number keys pick a tab, Shift-Tab goes back one, unless rebound
+    if app.keymap.action(&key).is_none() {
+        match key.code {
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                if let Some(&tab) = Tab::ALL.get(index) {
+                    app.switch_tab(tab);
+                }
+                return;
+            }
+            KeyCode::BackTab => {
+                app.previous_tab();
+                return;
+            }
+            _ => {}
         }
+    }
+
+    // Keys that keep working whatever the keymap says
+    let action = match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::Quit),
+        _ => app.keymap.action(&key).or(match key.code {
+            KeyCode::Esc => Some(Action::Quit),
+            KeyCode::Down => Some(Action::Down),
+            KeyCode::Up => Some(Action::Up),
+            KeyCode::PageDown => Some(Action::PageDown),
+            KeyCode::PageUp => Some(Action::PageUp),
+            _ => None,
+        }),
+    };
+
+    let Some(action) = action else {
+        return;
+    };
+
+    match action {
+        Action::Quit => app.quit(),
 
-        // Ctrl+C to quit
+        // Navigation
+        Action::Down => app.next(),
+        Action::Up => app.previous(),
+        Action::First => app.go_to_first(),
+        Action::Last => app.go_to_last(),
+        Action::PreviousSection => app.previous_section(),
+        Action::NextSection => app.next_section(),
+
+        // Page navigation
+        Action::PageDown => app.page_down(),
+        Action::PageUp => app.page_up(),
+
+        // Actions
+        Action::Open => app.handle_enter(),
+        Action::ToggleExpand => app.toggle_expand(),
+        Action::OpenInBrowser => app.open_in_browser(),
+        Action::CollapseAll => app.collapse_all(),
+        Action::Refresh => app.mark_for_refresh(),
+        Action::ToggleMode => app.toggle_mode(),
+        Action::GoToDate => app.open_date_prompt(),
+        Action::ToggleLog => app.toggle_log(),
+        Action::Search => app.open_search(),
+        Action::NextMatch => app.next_match(),
+        Action::PreviousMatch => app.previous_match(),
+        Action::Filter => app.open_facet_picker(),
+        Action::Sort => app.cycle_sort(),
+        Action::ToggleRead => app.toggle_read(),
+        Action::MarkAllRead => app.mark_all_read(),
+        Action::ToggleBookmark => app.toggle_bookmark(),
+        Action::ToggleSaved => app.toggle_saved(),
+        Action::NextTab => app.next_tab(),
+        Action::ToggleSplit => app.toggle_split(),
+        Action::GrowList => app.grow_list(),
+        Action::ShrinkList => app.shrink_list(),
+        Action::ScrollDetailDown => app.scroll_detail_down(),
+        Action::ScrollDetailUp => app.scroll_detail_up(),
+    }
+}
+
+/// Handle key events while the "go to date" prompt is open
+fn handle_date_prompt_key(app: &mut App, key: KeyEvent) {
+    match key.code {
         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.quit();
         }
-
-        // Navigation
-        KeyCode::Down | KeyCode::Char('j') => {
-            app.next();
+        KeyCode::Esc => {
+            app.close_date_prompt();
         }
-        KeyCode::Up | KeyCode::Char('k') => {
-            app.previous();
+        KeyCode::Enter => {
+            app.submit_date_prompt();
         }
-        KeyCode::Char('g') => {
-            app.go_to_first();
+        KeyCode::Backspace => {
+            if let Some(prompt) = app.date_prompt.as_mut() {
+                prompt.input.pop();
+                prompt.error = None;
+            }
         }
-        KeyCode::Char('G') => {
-            app.go_to_last();
+        KeyCode::Char(c) => {
+            if let Some(prompt) = app.date_prompt.as_mut() {
+                prompt.input.push(c);
+                prompt.error = None;
+            }
         }
+        _ => {}
+    }
+}
 
-        // Page navigation
-        KeyCode::PageDown | KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.page_down();
+/// Handle key events while the `/` search prompt is open
+fn handle_search_prompt_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.quit();
         }
-        KeyCode::PageUp | KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.page_up();
+        KeyCode::Esc => {
+            app.cancel_search();
         }
-
-        // Actions
         KeyCode::Enter => {
-            app.handle_enter();
-        }
-        KeyCode::Char(' ') => {
-            app.toggle_expand();
-        }
-        KeyCode::Char('o') => {
-            app.open_in_browser();
+            app.submit_search();
         }
-        KeyCode::Char('x') => {
-            app.collapse_all();
+        KeyCode::Backspace => {
+            if let Some(prompt) = app.search_prompt.as_mut() {
+                prompt.input.pop();
+            }
+            app.update_search();
         }
-        KeyCode::Char('r') => {
-            app.mark_for_refresh();
+        KeyCode::Char(c) => {
+            if let Some(prompt) = app.search_prompt.as_mut() {
+                prompt.input.push(c);
+            }
+            app.update_search();
         }
-
         _ => {}
     }
 }
 
+/// Handle key events while the tag/source picker is open. Movement follows
+/// the keymap; Space cycles include/exclude, `c` clears, Enter applies.
+fn handle_facet_picker_key(app: &mut App, key: KeyEvent) {
+    let action = app.keymap.action(&key);
+    let Some(picker) = app.facet_picker.as_mut() else {
+        return;
+    };
+
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.quit(),
+        KeyCode::Esc => app.close_facet_picker(),
+        KeyCode::Enter => app.submit_facet_picker(),
+        KeyCode::Down => picker.next(),
+        KeyCode::Up => picker.previous(),
+        KeyCode::Char(' ') => picker.toggle(),
+        KeyCode::Char('c') => picker.clear(),
+        _ => match action {
+            Some(Action::Down) => picker.next(),
+            Some(Action::Up) => picker.previous(),
+            Some(Action::Quit | Action::Filter) => app.close_facet_picker(),
+            _ => {}
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::app::ViewMode;
     use crossterm::event::{KeyEventKind, KeyEventState};
 
     fn create_key(code: KeyCode) -> KeyEvent {
@@ -79,4 +208,122 @@ mod tests {
         handle_key_event(&mut app, create_key(KeyCode::Char('q')));
         assert!(app.should_quit);
     }
+
+    #[test]
+    fn test_key_dismisses_error() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.last_error = Some("Failed to save bookmarks".to_string());
+
+        handle_key_event(&mut app, create_key(KeyCode::Esc));
+        assert!(app.last_error.is_none());
+        assert!(!app.should_quit);
+
+        handle_key_event(&mut app, create_key(KeyCode::Esc));
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_toggle_mode_event() {
+        let mut app = App::new("http://localhost:3000", 50);
+        assert_eq!(app.mode, ViewMode::Latest);
+
+        handle_key_event(&mut app, create_key(KeyCode::Char('t')));
+        assert_eq!(app.mode, ViewMode::Timeline);
+        assert!(app.needs_refresh);
+    }
+
+    #[test]
+    fn test_remapped_keys() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.keymap =
+            crate::keymap::Keymap::with_overrides(&[("quit".to_string(), "Q".to_string())].into())
+                .unwrap();
+
+        handle_key_event(&mut app, create_key(KeyCode::Char('q')));
+        assert!(!app.should_quit);
+        handle_key_event(&mut app, create_key(KeyCode::Char('Q')));
+        assert!(app.should_quit);
+
+        // Esc quits whatever the keymap says
+        let mut app = App::new("http://localhost:3000", 50);
+        app.keymap =
+            crate::keymap::Keymap::with_overrides(&[("quit".to_string(), "Q".to_string())].into())
+                .unwrap();
+        handle_key_event(&mut app, create_key(KeyCode::Esc));
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_search_keys() {
+        let mut app = App::new("http://localhost:3000", 50);
+
+        handle_key_event(&mut app, create_key(KeyCode::Char('/')));
+        assert!(app.search_prompt.is_some());
+
+        // 'q' is part of the query, Enter keeps the query active
+        handle_key_event(&mut app, create_key(KeyCode::Char('q')));
+        assert!(!app.should_quit);
+        assert_eq!(app.search, "q");
+        handle_key_event(&mut app, create_key(KeyCode::Enter));
+        assert!(app.search_prompt.is_none());
+        assert_eq!(app.search, "q");
+
+        // The first Esc clears the search, the second quits
+        handle_key_event(&mut app, create_key(KeyCode::Esc));
+        assert!(app.search.is_empty());
+        assert!(!app.should_quit);
+        handle_key_event(&mut app, create_key(KeyCode::Esc));
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_facet_picker_keys() {
+        let mut app = App::new("http://localhost:3000", 50);
+
+        handle_key_event(&mut app, create_key(KeyCode::Char('f')));
+        assert!(app.facet_picker.is_some());
+
+        // 'q' closes the picker instead of quitting
+        handle_key_event(&mut app, create_key(KeyCode::Char('q')));
+        assert!(app.facet_picker.is_none());
+        assert!(!app.should_quit);
+
+        handle_key_event(&mut app, create_key(KeyCode::Char('f')));
+        handle_key_event(&mut app, create_key(KeyCode::Enter));
+        assert!(app.facet_picker.is_none());
+        assert!(app.filters.is_empty());
+    }
+
+    #[test]
+    fn test_tab_keys() {
+        let mut app = App::new("http://localhost:3000", 50);
+
+        handle_key_event(&mut app, create_key(KeyCode::Char('3')));
+        assert_eq!(app.tab, Tab::Saved);
+        handle_key_event(&mut app, create_key(KeyCode::Tab));
+        assert_eq!(app.tab, Tab::Search);
+        handle_key_event(&mut app, create_key(KeyCode::BackTab));
+        assert_eq!(app.tab, Tab::Saved);
+        handle_key_event(&mut app, create_key(KeyCode::Char('9')));
+        assert_eq!(app.tab, Tab::Saved);
+        handle_key_event(&mut app, create_key(KeyCode::Char('1')));
+        assert_eq!(app.tab, Tab::Latest);
+    }
+
+    #[test]
+    fn test_date_prompt_captures_keys() {
+        let mut app = App::new("http://localhost:3000", 50);
+
+        handle_key_event(&mut app, create_key(KeyCode::Char('D')));
+        assert!(app.date_prompt.is_some());
+
+        // 'q' is typed into the prompt instead of quitting
+        handle_key_event(&mut app, create_key(KeyCode::Char('q')));
+        assert!(!app.should_quit);
+        assert_eq!(app.date_prompt.as_ref().unwrap().input, "q");
+
+        handle_key_event(&mut app, create_key(KeyCode::Esc));
+        assert!(app.date_prompt.is_none());
+        assert!(!app.should_quit);
+    }
 }
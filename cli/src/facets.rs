@@ -0,0 +1,246 @@
+use crate::api::Article;
+use crate::config::Filters;
+use ratatui::widgets::ListState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FacetKind {
+    Tag,
+    Source,
+}
+
+impl FacetKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            FacetKind::Tag => "Tag",
+            FacetKind::Source => "Source",
+        }
+    }
+
+    fn value(self, article: &Article) -> &str {
+        match self {
+            FacetKind::Tag => &article.tag,
+            FacetKind::Source => &article.source,
+        }
+    }
+}
+
+/// What the filter does with articles carrying a facet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Selection {
+    #[default]
+    Any,
+    Include,
+    Exclude,
+}
+
+impl Selection {
+    fn next(self) -> Self {
+        match self {
+            Selection::Any => Selection::Include,
+            Selection::Include => Selection::Exclude,
+            Selection::Exclude => Selection::Any,
+        }
+    }
+}
+
+/// One tag or source, with how many loaded articles carry it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Facet {
+    pub kind: FacetKind,
+    pub name: String,
+    pub count: usize,
+    pub selection: Selection,
+}
+
+/// State of the `f` popup: every tag and source, each included, excluded or neither
+#[derive(Debug, Clone, Default)]
+pub struct FacetPicker {
+    pub facets: Vec<Facet>,
+    pub list_state: ListState,
+}
+
+impl FacetPicker {
+    /// Tags then sources found in `articles`, most common first, with the
+    /// values of `filters` preselected. Filter values no article carries are
+    /// kept with a count of 0 so opening and applying the picker doesn't drop them.
+    pub fn new(articles: &[Article], filters: &Filters) -> Self {
+        let mut facets: Vec<Facet> = Vec::new();
+        for article in articles {
+            for kind in [FacetKind::Tag, FacetKind::Source] {
+                let name = kind.value(article);
+                match facets
+                    .iter_mut()
+                    .find(|f| f.kind == kind && f.name.eq_ignore_ascii_case(name))
+                {
+                    Some(facet) => facet.count += 1,
+                    None => facets.push(Facet {
+                        kind,
+                        name: name.to_string(),
+                        count: 1,
+                        selection: Selection::Any,
+                    }),
+                }
+            }
+        }
+
+        let selected = [
+            (FacetKind::Tag, Selection::Include, &filters.tags),
+            (FacetKind::Tag, Selection::Exclude, &filters.exclude_tags),
+            (FacetKind::Source, Selection::Include, &filters.sources),
+            (
+                FacetKind::Source,
+                Selection::Exclude,
+                &filters.exclude_sources,
+            ),
+        ];
+        for (kind, selection, names) in selected {
+            for name in names {
+                match facets
+                    .iter_mut()
+                    .find(|f| f.kind == kind && f.name.eq_ignore_ascii_case(name))
+                {
+                    Some(facet) => facet.selection = selection,
+                    None => facets.push(Facet {
+                        kind,
+                        name: name.clone(),
+                        count: 0,
+                        selection,
+                    }),
+                }
+            }
+        }
+
+        facets.sort_by(|a, b| {
+            a.kind
+                .cmp(&b.kind)
+                .then(b.count.cmp(&a.count))
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+
+        let mut list_state = ListState::default();
+        list_state.select((!facets.is_empty()).then_some(0));
+        Self { facets, list_state }
+    }
+
+    pub fn next(&mut self) {
+        let i = self.list_state.selected().unwrap_or(0);
+        if i + 1 < self.facets.len() {
+            self.list_state.select(Some(i + 1));
+        }
+    }
+
+    pub fn previous(&mut self) {
+        let i = self.list_state.selected().unwrap_or(0);
+        self.list_state.select(Some(i.saturating_sub(1)));
+    }
+
+    /// Cycle the selected facet through include, exclude and neither
+    pub fn toggle(&mut self) {
+        if let Some(facet) = self
+            .list_state
+            .selected()
+            .and_then(|i| self.facets.get_mut(i))
+        {
+            facet.selection = facet.selection.next();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        for facet in &mut self.facets {
+            facet.selection = Selection::Any;
+        }
+    }
+
+    /// The filter the current selections describe
+    pub fn filters(&self) -> Filters {
+        let mut filters = Filters::default();
+        for facet in &self.facets {
+            let list = match (facet.kind, facet.selection) {
+                (_, Selection::Any) => continue,
+                (FacetKind::Tag, Selection::Include) => &mut filters.tags,
+                (FacetKind::Tag, Selection::Exclude) => &mut filters.exclude_tags,
+                (FacetKind::Source, Selection::Include) => &mut filters.sources,
+                (FacetKind::Source, Selection::Exclude) => &mut filters.exclude_sources,
+            };
+            list.push(facet.name.clone());
+        }
+        filters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    fn article(tag: &str, source: &str) -> Article {
+        Article {
+            tag: tag.to_string(),
+            source: source.to_string(),
+            ..test_support::article("https://example.com")
+        }
+    }
+
+    #[test]
+    fn test_facets_are_counted_and_sorted() {
+        let articles = [
+            article("Tech", "Hacker News"),
+            article("AI", "arXiv"),
+            article("AI", "Hacker News"),
+            article("ai", "Lobsters"),
+        ];
+        let picker = FacetPicker::new(&articles, &Filters::default());
+
+        let facets: Vec<(FacetKind, &str, usize)> = picker
+            .facets
+            .iter()
+            .map(|f| (f.kind, f.name.as_str(), f.count))
+            .collect();
+        assert_eq!(
+            facets,
+            vec![
+                (FacetKind::Tag, "AI", 3),
+                (FacetKind::Tag, "Tech", 1),
+                (FacetKind::Source, "Hacker News", 2),
+                (FacetKind::Source, "arXiv", 1),
+                (FacetKind::Source, "Lobsters", 1),
+            ]
+        );
+        assert_eq!(picker.list_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_picker_round_trips_filters() {
+        let articles = [article("AI", "arXiv"), article("Tech", "Hacker News")];
+        let filters = Filters {
+            tags: vec!["ai".to_string()],
+            exclude_sources: vec!["Reddit".to_string()],
+            ..Filters::default()
+        };
+        let mut picker = FacetPicker::new(&articles, &filters);
+
+        // Values from the filter that no article has are kept
+        let reddit = picker.facets.iter().find(|f| f.name == "Reddit").unwrap();
+        assert_eq!((reddit.count, reddit.selection), (0, Selection::Exclude));
+        assert_eq!(
+            picker.filters(),
+            Filters {
+                tags: vec!["AI".to_string()],
+                exclude_sources: vec!["Reddit".to_string()],
+                ..Filters::default()
+            }
+        );
+
+        // AI: include -> exclude
+        picker.toggle();
+        // Tech: any -> include
+        picker.next();
+        picker.toggle();
+        let filters = picker.filters();
+        assert_eq!(filters.tags, vec!["Tech"]);
+        assert_eq!(filters.exclude_tags, vec!["AI"]);
+
+        picker.clear();
+        assert!(picker.filters().is_empty());
+    }
+}
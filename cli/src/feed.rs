@@ -0,0 +1,285 @@
+use crate::api::Article;
+use crate::commands::{self, write_stdout};
+use crate::config::{Config, FeedArgs, FeedFormat};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::fs;
+use std::io::{self, Write};
+
+const FEED_TITLE: &str = "Potluck";
+const FEED_SUBTITLE: &str = "AI-curated tech news";
+
+/// Feed-level metadata
+#[derive(Debug, Clone)]
+pub struct FeedInfo {
+    pub title: String,
+    /// Site the feed describes; also the Atom feed id
+    pub link: String,
+    /// Where the feed itself is published, if known
+    pub self_link: Option<String>,
+    pub updated: DateTime<Utc>,
+}
+
+impl FeedInfo {
+    /// Metadata for `articles` served from `link`. `updated` is the newest
+    /// publication time, or `now` when nothing has a valid timestamp.
+    pub fn new(link: &str, articles: &[Article], now: DateTime<Utc>) -> Self {
+        Self {
+            title: FEED_TITLE.to_string(),
+            link: link.trim_end_matches('/').to_string(),
+            self_link: None,
+            updated: articles
+                .iter()
+                .filter_map(|a| parse_time(&a.published_at))
+                .max()
+                .unwrap_or(now),
+        }
+    }
+}
+
+/// `ptlk feed`: print the fetched articles as an Atom or RSS document
+pub async fn run(config: &Config, args: &FeedArgs) -> Result<()> {
+    let articles = commands::load_articles(config).await?;
+    let info = FeedInfo {
+        self_link: args.feed_url.clone(),
+        ..FeedInfo::new(&config.api_url, &articles, Utc::now())
+    };
+
+    match &args.output {
+        Some(path) => {
+            let mut out = Vec::new();
+            write_feed(&mut out, &info, &articles, args.format)?;
+            fs::write(path, out).with_context(|| format!("Failed to write {}", path.display()))
+        }
+        None => write_stdout(|out| write_feed(out, &info, &articles, args.format)),
+    }
+}
+
+pub fn write_feed(
+    out: &mut dyn Write,
+    info: &FeedInfo,
+    articles: &[Article],
+    format: FeedFormat,
+) -> io::Result<()> {
+    match format {
+        FeedFormat::Atom => write_atom(out, info, articles),
+        FeedFormat::Rss => write_rss(out, info, articles),
+    }
+}
+
+/// Atom 1.0 (RFC 4287)
+fn write_atom(out: &mut dyn Write, info: &FeedInfo, articles: &[Article]) -> io::Result<()> {
+    writeln!(out, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+    writeln!(out, r#"<feed xmlns="http://www.w3.org/2005/Atom">"#)?;
+    writeln!(out, "  <title>{}</title>", escape(&info.title))?;
+    writeln!(out, "  <subtitle>{}</subtitle>", FEED_SUBTITLE)?;
+    writeln!(out, "  <id>{}/</id>", escape(&info.link))?;
+    writeln!(out, r#"  <link href="{}/"/>"#, escape(&info.link))?;
+    if let Some(self_link) = &info.self_link {
+        writeln!(out, r#"  <link rel="self" href="{}"/>"#, escape(self_link))?;
+    }
+    writeln!(out, "  <updated>{}</updated>", atom_time(info.updated))?;
+    writeln!(out, "  <generator>ptlk</generator>")?;
+
+    for article in articles {
+        let published = parse_time(&article.published_at).unwrap_or(info.updated);
+        writeln!(out, "  <entry>")?;
+        writeln!(out, "    <title>{}</title>", escape(&article.title))?;
+        writeln!(out, "    <id>{}</id>", escape(&article.url))?;
+        writeln!(
+            out,
+            r#"    <link rel="alternate" href="{}"/>"#,
+            escape(&article.url)
+        )?;
+        writeln!(out, "    <published>{}</published>", atom_time(published))?;
+        writeln!(out, "    <updated>{}</updated>", atom_time(published))?;
+        writeln!(
+            out,
+            "    <author><name>{}</name></author>",
+            escape(&article.source)
+        )?;
+        writeln!(out, r#"    <category term="{}"/>"#, escape(&article.tag))?;
+        if !article.summary.is_empty() {
+            writeln!(
+                out,
+                r#"    <summary type="text">{}</summary>"#,
+                escape(&article.summary)
+            )?;
+        }
+        writeln!(out, "  </entry>")?;
+    }
+
+    writeln!(out, "</feed>")
+}
+
+/// RSS 2.0, with the source in `dc:creator` since `<author>` must be an email address
+fn write_rss(out: &mut dyn Write, info: &FeedInfo, articles: &[Article]) -> io::Result<()> {
+    writeln!(out, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">"#
+    )?;
+    writeln!(out, "  <channel>")?;
+    writeln!(out, "    <title>{}</title>", escape(&info.title))?;
+    writeln!(out, "    <link>{}/</link>", escape(&info.link))?;
+    writeln!(out, "    <description>{}</description>", FEED_SUBTITLE)?;
+    writeln!(
+        out,
+        "    <lastBuildDate>{}</lastBuildDate>",
+        info.updated.to_rfc2822()
+    )?;
+    writeln!(out, "    <generator>ptlk</generator>")?;
+
+    for article in articles {
+        let published = parse_time(&article.published_at).unwrap_or(info.updated);
+        writeln!(out, "    <item>")?;
+        writeln!(out, "      <title>{}</title>", escape(&article.title))?;
+        writeln!(out, "      <link>{}</link>", escape(&article.url))?;
+        writeln!(
+            out,
+            r#"      <guid isPermaLink="true">{}</guid>"#,
+            escape(&article.url)
+        )?;
+        writeln!(out, "      <pubDate>{}</pubDate>", published.to_rfc2822())?;
+        writeln!(
+            out,
+            "      <dc:creator>{}</dc:creator>",
+            escape(&article.source)
+        )?;
+        writeln!(out, "      <category>{}</category>", escape(&article.tag))?;
+        if !article.summary.is_empty() {
+            writeln!(
+                out,
+                "      <description>{}</description>",
+                escape(&article.summary)
+            )?;
+        }
+        writeln!(out, "    </item>")?;
+    }
+
+    writeln!(out, "  </channel>")?;
+    writeln!(out, "</rss>")
+}
+
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn atom_time(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// Escape text and attribute values, dropping characters XML 1.0 forbids
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(ch),
+            c if c < ' ' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::article;
+
+    fn articles() -> Vec<Article> {
+        vec![
+            Article {
+                title: "Rust & <WebAssembly>".to_string(),
+                summary: "Fast\u{0}er \"code\"".to_string(),
+                tag: "Dev".to_string(),
+                source: "Hacker News".to_string(),
+                published_at: "2026-10-17T08:00:00+08:00".to_string(),
+                ..article("https://example.com/a?x=1&y=2")
+            },
+            Article {
+                title: "量子位".to_string(),
+                summary: String::new(),
+                tag: "AI".to_string(),
+                source: "QbitAI".to_string(),
+                published_at: "not a date".to_string(),
+                ..article("https://example.com/b")
+            },
+        ]
+    }
+
+    fn render(format: FeedFormat) -> String {
+        let articles = articles();
+        let info = FeedInfo::new("https://potluck.example/", &articles, Utc::now());
+        let mut out = Vec::new();
+        write_feed(&mut out, &info, &articles, format).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_feed_updated_is_newest_article() {
+        let info = FeedInfo::new("https://potluck.example", &articles(), Utc::now());
+        assert_eq!(atom_time(info.updated), "2026-10-17T00:00:00Z");
+    }
+
+    #[test]
+    fn test_atom_feed() {
+        let atom = render(FeedFormat::Atom);
+
+        assert!(atom.starts_with("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+        assert!(atom.contains("<id>https://potluck.example/</id>"));
+        assert!(atom.contains("<updated>2026-10-17T00:00:00Z</updated>"));
+        assert!(atom.contains("<title>Rust &amp; &lt;WebAssembly&gt;</title>"));
+        assert!(
+            atom.contains("<link rel=\"alternate\" href=\"https://example.com/a?x=1&amp;y=2\"/>")
+        );
+        assert!(atom.contains("<category term=\"Dev\"/>"));
+        assert!(atom.contains("<author><name>Hacker News</name></author>"));
+        assert!(atom.contains("<summary type=\"text\">Faster &quot;code&quot;</summary>"));
+        // Unparseable dates fall back to the feed's updated time
+        assert!(atom.contains("<published>2026-10-17T00:00:00Z</published>"));
+        assert_eq!(atom.matches("<entry>").count(), 2);
+        assert_eq!(atom.matches("<summary").count(), 1);
+        assert!(atom.ends_with("</feed>\n"));
+        // Without a feed URL there's nothing to point the self link at
+        assert!(!atom.contains("rel=\"self\""));
+    }
+
+    #[test]
+    fn test_atom_self_link() {
+        let articles = articles();
+        let info = FeedInfo {
+            self_link: Some("https://potluck.example/feed.atom?a=1&b=2".to_string()),
+            ..FeedInfo::new("https://potluck.example/", &articles, Utc::now())
+        };
+        let mut out = Vec::new();
+        write_feed(&mut out, &info, &articles, FeedFormat::Atom).unwrap();
+        let atom = String::from_utf8(out).unwrap();
+
+        assert!(atom.contains(
+            "<link rel=\"self\" href=\"https://potluck.example/feed.atom?a=1&amp;b=2\"/>"
+        ));
+    }
+
+    #[test]
+    fn test_rss_feed() {
+        let rss = render(FeedFormat::Rss);
+
+        assert!(rss.contains("<rss version=\"2.0\""));
+        assert!(rss.contains("<lastBuildDate>Sat, 17 Oct 2026 00:00:00 +0000</lastBuildDate>"));
+        assert!(rss.contains("<guid isPermaLink=\"true\">https://example.com/a?x=1&amp;y=2</guid>"));
+        assert!(rss.contains("<pubDate>Sat, 17 Oct 2026 00:00:00 +0000</pubDate>"));
+        assert!(rss.contains("<dc:creator>QbitAI</dc:creator>"));
+        assert!(rss.contains("<category>AI</category>"));
+        assert!(rss.contains("<title>量子位</title>"));
+        assert_eq!(rss.matches("<item>").count(), 2);
+        assert!(rss.ends_with("</channel>\n</rss>\n"));
+    }
+}
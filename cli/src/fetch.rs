@@ -0,0 +1,99 @@
+use crate::api::{ApiClient, ApiError, Article, DateRange, Pagination};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Which endpoint and position to fetch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchRequest {
+    Latest { page: u32, limit: u32 },
+    Timeline { cursor: Option<String>, limit: u32 },
+    Range(DateRange),
+}
+
+/// What to do with a fetched page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchKind {
+    /// Replace the list
+    Reload,
+    /// Append to the list
+    More,
+    /// Background check for new articles; the list is left alone
+    Poll,
+}
+
+/// A fetch queued by [`crate::app::App`] for the event loop to run in the background
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchJob {
+    /// Reload generation the job belongs to; results from older generations are dropped
+    pub generation: u64,
+    pub kind: FetchKind,
+    pub request: FetchRequest,
+}
+
+/// One page of articles plus whatever the endpoint says about the next one
+#[derive(Debug, Clone, Default)]
+pub struct Page {
+    pub articles: Vec<Article>,
+    /// Page-based position, only for `/api/latest`
+    pub pagination: Option<Pagination>,
+    /// Cursor-based position, only for `/api/timeline`
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+/// A finished [`FetchJob`], sent back to the event loop
+#[derive(Debug)]
+pub struct FetchResult {
+    pub generation: u64,
+    pub kind: FetchKind,
+    pub result: Result<Page, ApiError>,
+}
+
+/// Run `request` against the API
+pub async fn run(client: &ApiClient, request: FetchRequest) -> Result<Page, ApiError> {
+    match request {
+        FetchRequest::Latest { page, limit } => {
+            let response = client.fetch_latest(page, limit).await?;
+            Ok(Page {
+                has_more: response.pagination.page < response.pagination.total_pages,
+                articles: response.data,
+                pagination: Some(response.pagination),
+                next_cursor: None,
+            })
+        }
+        FetchRequest::Timeline { cursor, limit } => {
+            let response = client.fetch_timeline(cursor.as_deref(), limit).await?;
+            Ok(Page {
+                has_more: response.has_more && response.next_cursor.is_some(),
+                articles: response.data,
+                pagination: None,
+                next_cursor: response.next_cursor,
+            })
+        }
+        // Ranges arrive in a single response
+        FetchRequest::Range(range) => {
+            let response = client.fetch_timeline_range(range).await?;
+            Ok(Page {
+                articles: response.data,
+                ..Page::default()
+            })
+        }
+    }
+}
+
+/// Run `job` on a tokio task and report the outcome on `tx`
+pub fn spawn(client: ApiClient, job: FetchJob, tx: UnboundedSender<FetchResult>) {
+    // Background polls retry quietly; the status line is for fetches the user started
+    let client = match job.kind {
+        FetchKind::Poll => client.detached(),
+        FetchKind::Reload | FetchKind::More => client,
+    };
+    tokio::spawn(async move {
+        let result = run(&client, job.request).await;
+        // The receiver is gone only when the app is shutting down
+        let _ = tx.send(FetchResult {
+            generation: job.generation,
+            kind: job.kind,
+            result,
+        });
+    });
+}
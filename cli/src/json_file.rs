@@ -0,0 +1,55 @@
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// Load the JSON file at `path`, or `None` if there's no file yet. One that
+/// can't be read or parsed is an error, so callers can keep the next save
+/// from overwriting it.
+pub fn load<T: DeserializeOwned>(path: &Path) -> Result<Option<T>> {
+    match fs::read_to_string(path) {
+        Ok(json) => serde_json::from_str(&json)
+            .map(Some)
+            .with_context(|| format!("Failed to parse {}", path.display())),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    }
+}
+
+/// Write `value` to `path` as JSON, creating the directory if needed and
+/// replacing the file atomically
+pub fn write_json_atomic<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+
+    let json = serde_json::to_string(value)?;
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, json).with_context(|| format!("Failed to write {}", tmp.display()))?;
+    fs::rename(&tmp, path).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_json_file_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("state.json");
+        assert!(load::<BTreeMap<String, u32>>(&path).unwrap().is_none());
+
+        let value = BTreeMap::from([("read".to_string(), 3)]);
+        write_json_atomic(&path, &value).unwrap();
+        assert_eq!(load(&path).unwrap(), Some(value));
+        assert!(!path.with_extension("json.tmp").exists());
+
+        fs::write(&path, "{ not json").unwrap();
+        let err = load::<BTreeMap<String, u32>>(&path).unwrap_err();
+        assert!(format!("{:#}", err).contains("Failed to parse"));
+    }
+}
@@ -0,0 +1,408 @@
+use anyhow::{bail, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Something a key can be bound to in the article list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Action {
+    Quit,
+    Down,
+    Up,
+    First,
+    Last,
+    PreviousSection,
+    NextSection,
+    PageDown,
+    PageUp,
+    Open,
+    ToggleExpand,
+    OpenInBrowser,
+    CollapseAll,
+    Refresh,
+    ToggleMode,
+    GoToDate,
+    ToggleLog,
+    Search,
+    NextMatch,
+    PreviousMatch,
+    Filter,
+    Sort,
+    ToggleRead,
+    MarkAllRead,
+    ToggleBookmark,
+    ToggleSaved,
+    NextTab,
+    ToggleSplit,
+    GrowList,
+    ShrinkList,
+    ScrollDetailDown,
+    ScrollDetailUp,
+}
+
+impl Action {
+    pub const ALL: [Action; 32] = [
+        Action::Quit,
+        Action::Down,
+        Action::Up,
+        Action::First,
+        Action::Last,
+        Action::PreviousSection,
+        Action::NextSection,
+        Action::PageDown,
+        Action::PageUp,
+        Action::Open,
+        Action::ToggleExpand,
+        Action::OpenInBrowser,
+        Action::CollapseAll,
+        Action::Refresh,
+        Action::ToggleMode,
+        Action::GoToDate,
+        Action::ToggleLog,
+        Action::Search,
+        Action::NextMatch,
+        Action::PreviousMatch,
+        Action::Filter,
+        Action::Sort,
+        Action::ToggleRead,
+        Action::MarkAllRead,
+        Action::ToggleBookmark,
+        Action::ToggleSaved,
+        Action::NextTab,
+        Action::ToggleSplit,
+        Action::GrowList,
+        Action::ShrinkList,
+        Action::ScrollDetailDown,
+        Action::ScrollDetailUp,
+    ];
+
+    /// Name used in the `[keybindings]` table of the config file
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Down => "down",
+            Action::Up => "up",
+            Action::First => "first",
+            Action::Last => "last",
+            Action::PreviousSection => "previous_section",
+            Action::NextSection => "next_section",
+            Action::PageDown => "page_down",
+            Action::PageUp => "page_up",
+            Action::Open => "open",
+            Action::ToggleExpand => "toggle_expand",
+            Action::OpenInBrowser => "open_in_browser",
+            Action::CollapseAll => "collapse_all",
+            Action::Refresh => "refresh",
+            Action::ToggleMode => "toggle_mode",
+            Action::GoToDate => "go_to_date",
+            Action::ToggleLog => "toggle_log",
+            Action::Search => "search",
+            Action::NextMatch => "next_match",
+            Action::PreviousMatch => "previous_match",
+            Action::Filter => "filter",
+            Action::Sort => "sort",
+            Action::ToggleRead => "toggle_read",
+            Action::MarkAllRead => "mark_all_read",
+            Action::ToggleBookmark => "toggle_bookmark",
+            Action::ToggleSaved => "toggle_saved",
+            Action::NextTab => "next_tab",
+            Action::ToggleSplit => "toggle_split",
+            Action::GrowList => "grow_list",
+            Action::ShrinkList => "shrink_list",
+            Action::ScrollDetailDown => "scroll_detail_down",
+            Action::ScrollDetailUp => "scroll_detail_up",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+
+    fn default_key(self) -> KeyBinding {
+        let key = |c| KeyBinding::new(KeyCode::Char(c));
+        match self {
+            Action::Quit => key('q'),
+            Action::Down => key('j'),
+            Action::Up => key('k'),
+            Action::First => key('g'),
+            Action::Last => key('G'),
+            Action::PreviousSection => key('['),
+            Action::NextSection => key(']'),
+            Action::PageDown => KeyBinding::ctrl('d'),
+            Action::PageUp => KeyBinding::ctrl('u'),
+            Action::Open => KeyBinding::new(KeyCode::Enter),
+            Action::ToggleExpand => key(' '),
+            Action::OpenInBrowser => key('o'),
+            Action::CollapseAll => key('x'),
+            Action::Refresh => key('r'),
+            Action::ToggleMode => key('t'),
+            Action::GoToDate => key('D'),
+            Action::ToggleLog => key('L'),
+            Action::Search => key('/'),
+            Action::NextMatch => key('n'),
+            Action::PreviousMatch => key('N'),
+            Action::Filter => key('f'),
+            Action::Sort => key('s'),
+            Action::ToggleRead => key('m'),
+            Action::MarkAllRead => key('M'),
+            Action::ToggleBookmark => key('b'),
+            Action::ToggleSaved => key('S'),
+            Action::NextTab => KeyBinding::new(KeyCode::Tab),
+            Action::ToggleSplit => key('v'),
+            Action::GrowList => key('>'),
+            Action::ShrinkList => key('<'),
+            Action::ScrollDetailDown => key('J'),
+            Action::ScrollDetailUp => key('K'),
+        }
+    }
+}
+
+/// A key with an optional Ctrl modifier, written `j`, `G`, `ctrl-d`, `enter`, `space`, ...
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub ctrl: bool,
+}
+
+impl KeyBinding {
+    fn new(code: KeyCode) -> Self {
+        Self { code, ctrl: false }
+    }
+
+    fn ctrl(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            ctrl: true,
+        }
+    }
+
+    pub fn parse(spec: &str) -> Result<Self> {
+        let prefix = spec.get(..5).map(str::to_ascii_lowercase);
+        let (ctrl, key) = match prefix.as_deref() {
+            Some("ctrl-" | "ctrl+") => (true, &spec[5..]),
+            _ => (false, spec),
+        };
+
+        let code = match key.to_ascii_lowercase().as_str() {
+            "enter" => KeyCode::Enter,
+            "space" => KeyCode::Char(' '),
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "esc" => KeyCode::Esc,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => bail!("Unknown key '{}'", spec),
+                }
+            }
+        };
+        Ok(Self { code, ctrl })
+    }
+
+    /// Display form for the UI: `j`, `Enter`, `Space`, `Ctrl-d`
+    pub fn label(&self) -> String {
+        let key = Self {
+            ctrl: false,
+            ..*self
+        }
+        .to_string();
+        let mut chars = key.chars();
+        let key = match (chars.next(), chars.as_str()) {
+            (Some(first), rest) if !rest.is_empty() => {
+                format!("{}{}", first.to_ascii_uppercase(), rest)
+            }
+            _ => key,
+        };
+
+        if self.ctrl {
+            format!("Ctrl-{}", key)
+        } else {
+            key
+        }
+    }
+
+    /// Shift is ignored: terminals report `G` as Shift+`G`
+    fn matches(&self, key: &KeyEvent) -> bool {
+        self.code == key.code && self.ctrl == key.modifiers.contains(KeyModifiers::CONTROL)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "ctrl-")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Enter => write!(f, "enter"),
+            KeyCode::Tab => write!(f, "tab"),
+            KeyCode::Backspace => write!(f, "backspace"),
+            KeyCode::Esc => write!(f, "esc"),
+            KeyCode::Up => write!(f, "up"),
+            KeyCode::Down => write!(f, "down"),
+            KeyCode::Left => write!(f, "left"),
+            KeyCode::Right => write!(f, "right"),
+            KeyCode::PageUp => write!(f, "pageup"),
+            KeyCode::PageDown => write!(f, "pagedown"),
+            KeyCode::Home => write!(f, "home"),
+            KeyCode::End => write!(f, "end"),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// One key per [`Action`], starting from the built-in defaults
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: BTreeMap<Action, KeyBinding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            bindings: Action::ALL
+                .into_iter()
+                .map(|action| (action, action.default_key()))
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// Defaults with the `[keybindings]` table from the config file applied.
+    /// Unknown actions, unparseable keys and keys bound twice are errors.
+    pub fn with_overrides(overrides: &BTreeMap<String, String>) -> Result<Self> {
+        let mut keymap = Self::default();
+        for (name, spec) in overrides {
+            let Some(action) = Action::from_name(name) else {
+                bail!("Unknown action '{}' in [keybindings]", name);
+            };
+            keymap.bindings.insert(action, KeyBinding::parse(spec)?);
+        }
+
+        for (action, key) in &keymap.bindings {
+            if let Some((other, _)) = keymap
+                .bindings
+                .iter()
+                .find(|(other, other_key)| *other < action && *other_key == key)
+            {
+                bail!(
+                    "Key '{}' is bound to both {} and {}",
+                    key,
+                    other.name(),
+                    action.name()
+                );
+            }
+        }
+        Ok(keymap)
+    }
+
+    /// The action bound to `key`, if any
+    pub fn action(&self, key: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(_, binding)| binding.matches(key))
+            .map(|(action, _)| *action)
+    }
+
+    pub fn key(&self, action: Action) -> KeyBinding {
+        self.bindings[&action]
+    }
+
+    /// Every binding, in [`Action::ALL`] order
+    pub fn bindings(&self) -> impl Iterator<Item = (Action, KeyBinding)> + '_ {
+        self.bindings.iter().map(|(action, key)| (*action, *key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyEventKind, KeyEventState};
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::empty(),
+        }
+    }
+
+    #[test]
+    fn test_parse_key_binding() {
+        assert_eq!(
+            KeyBinding::parse("j").unwrap(),
+            KeyBinding::new(KeyCode::Char('j'))
+        );
+        assert_eq!(KeyBinding::parse("ctrl-d").unwrap(), KeyBinding::ctrl('d'));
+        assert_eq!(KeyBinding::parse("ctrl+d").unwrap(), KeyBinding::ctrl('d'));
+        assert_eq!(KeyBinding::parse("Ctrl-d").unwrap(), KeyBinding::ctrl('d'));
+        assert_eq!(
+            KeyBinding::parse("Enter").unwrap(),
+            KeyBinding::new(KeyCode::Enter)
+        );
+        assert!(KeyBinding::parse("jj").is_err());
+        assert!(KeyBinding::parse("").is_err());
+
+        for spec in ["G", "space", "ctrl-u", "pagedown"] {
+            assert_eq!(KeyBinding::parse(spec).unwrap().to_string(), spec);
+        }
+        assert_eq!(KeyBinding::parse("enter").unwrap().label(), "Enter");
+        assert_eq!(KeyBinding::parse("ctrl-d").unwrap().label(), "Ctrl-d");
+    }
+
+    #[test]
+    fn test_default_keymap() {
+        let keymap = Keymap::default();
+
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('j'), KeyModifiers::empty())),
+            Some(Action::Down)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            Some(Action::Last)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('d'), KeyModifiers::CONTROL)),
+            Some(Action::PageDown)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('d'), KeyModifiers::empty())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_keymap_overrides() {
+        let overrides = BTreeMap::from([
+            ("down".to_string(), "e".to_string()),
+            ("up".to_string(), "i".to_string()),
+        ]);
+        let keymap = Keymap::with_overrides(&overrides).unwrap();
+
+        assert_eq!(keymap.key(Action::Down).to_string(), "e");
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('j'), KeyModifiers::empty())),
+            None
+        );
+
+        let unknown = BTreeMap::from([("jump".to_string(), "n".to_string())]);
+        assert!(Keymap::with_overrides(&unknown).is_err());
+
+        let duplicate = BTreeMap::from([("down".to_string(), "k".to_string())]);
+        let err = Keymap::with_overrides(&duplicate).unwrap_err();
+        assert_eq!(err.to_string(), "Key 'k' is bound to both down and up");
+    }
+}
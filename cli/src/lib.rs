@@ -1,5 +1,22 @@
 pub mod api;
 pub mod app;
+pub mod bookmarks;
+pub mod cache;
+pub mod commands;
 pub mod config;
+pub mod digest;
+pub mod email;
 pub mod event;
+pub mod facets;
+pub mod feed;
+pub mod fetch;
+pub mod json_file;
+pub mod keymap;
+pub mod logging;
+pub mod markdown;
+pub mod read_state;
+pub mod search;
+#[cfg(test)]
+mod test_support;
+pub mod theme;
 pub mod ui;
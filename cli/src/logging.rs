@@ -0,0 +1,291 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use serde_json::{Map, Value};
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tokio::sync::Notify;
+
+/// Entries kept in memory for the log pane
+const MAX_RECENT: usize = 500;
+
+/// The log file is rotated to `<file>.1` once it grows past this
+const MAX_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+const LOG_FILE: &str = "debug.log";
+
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Level::Debug => "debug",
+            Level::Info => "info",
+            Level::Warn => "warn",
+            Level::Error => "error",
+        }
+    }
+}
+
+/// One structured log event
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub time: DateTime<Utc>,
+    pub level: Level,
+    /// Short event name such as `response` or `key`
+    pub event: &'static str,
+    pub fields: Map<String, Value>,
+}
+
+impl Entry {
+    /// One JSON object, as written to the log file
+    pub fn to_json(&self) -> String {
+        let mut object = Map::new();
+        object.insert("ts".to_string(), Value::from(self.time.to_rfc3339()));
+        object.insert("level".to_string(), Value::from(self.level.as_str()));
+        object.insert("event".to_string(), Value::from(self.event));
+        object.extend(self.fields.clone());
+        Value::Object(object).to_string()
+    }
+
+    /// `12:00:01 info response status=200 latency_ms=84`, for the log pane
+    pub fn summary(&self) -> String {
+        let mut line = format!(
+            "{} {:<5} {}",
+            self.time.with_timezone(&Local).format("%H:%M:%S"),
+            self.level.as_str(),
+            self.event
+        );
+        for (key, value) in &self.fields {
+            match value {
+                Value::String(text) => line.push_str(&format!(" {}={}", key, text)),
+                other => line.push_str(&format!(" {}={}", key, other)),
+            }
+        }
+        line
+    }
+}
+
+/// The open log file and how many bytes it holds
+struct LogFile {
+    file: File,
+    len: u64,
+}
+
+/// Appends JSON lines to a file and keeps the latest entries in memory
+pub struct Logger {
+    path: PathBuf,
+    max_bytes: u64,
+    file: Mutex<LogFile>,
+    recent: Mutex<VecDeque<Entry>>,
+    /// Woken on every entry, so the log pane can redraw
+    added: Notify,
+}
+
+impl Logger {
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        Self::with_max_bytes(path, MAX_FILE_BYTES)
+    }
+
+    fn with_max_bytes(path: impl Into<PathBuf>, max_bytes: u64) -> Result<Self> {
+        let path = path.into();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        let file = open_append(&path)?;
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+        Ok(Self {
+            path,
+            max_bytes,
+            file: Mutex::new(LogFile { file, len }),
+            recent: Mutex::new(VecDeque::with_capacity(MAX_RECENT)),
+            added: Notify::new(),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn record(&self, level: Level, event: &'static str, fields: Value) {
+        let entry = Entry {
+            time: Utc::now(),
+            level,
+            event,
+            fields: match fields {
+                Value::Object(fields) => fields,
+                _ => Map::new(),
+            },
+        };
+
+        // Logging must never take the app down; a failed write is dropped
+        if let Ok(mut log) = self.file.lock() {
+            let line = format!("{}\n", entry.to_json());
+            if log.len > 0 && log.len + line.len() as u64 > self.max_bytes {
+                self.rotate(&mut log);
+            }
+            if log.file.write_all(line.as_bytes()).is_ok() {
+                log.len += line.len() as u64;
+            }
+        }
+        if let Ok(mut recent) = self.recent.lock() {
+            if recent.len() == MAX_RECENT {
+                recent.pop_front();
+            }
+            recent.push_back(entry);
+        }
+        self.added.notify_one();
+    }
+
+    /// Move the full log to `<file>.1`, replacing the previous one, and start
+    /// a new file
+    fn rotate(&self, log: &mut LogFile) {
+        let mut old = self.path.clone().into_os_string();
+        old.push(".1");
+        if fs::rename(&self.path, old).is_err() {
+            return;
+        }
+        if let Ok(file) = open_append(&self.path) {
+            *log = LogFile { file, len: 0 };
+        }
+    }
+
+    /// Wait for the next entry
+    pub async fn added(&self) {
+        self.added.notified().await
+    }
+
+    /// The last `count` entries, oldest first
+    pub fn recent(&self, count: usize) -> Vec<Entry> {
+        let Ok(recent) = self.recent.lock() else {
+            return Vec::new();
+        };
+        let skip = recent.len().saturating_sub(count);
+        recent.iter().skip(skip).cloned().collect()
+    }
+}
+
+fn open_append(path: &Path) -> Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open log file {}", path.display()))
+}
+
+/// `$XDG_CACHE_HOME/ptlk/debug.log` (or the platform equivalent)
+pub fn default_path() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("ptlk").join(LOG_FILE))
+}
+
+/// Start logging to `path` for the rest of the process. Only the first call has an effect.
+pub fn init(path: impl Into<PathBuf>) -> Result<()> {
+    let logger = Logger::open(path)?;
+    let _ = LOGGER.set(logger);
+    Ok(())
+}
+
+/// The process-wide logger, if `--debug` turned it on
+pub fn logger() -> Option<&'static Logger> {
+    LOGGER.get()
+}
+
+/// Record an event; a no-op unless [`init`] was called.
+/// `fields` should be a JSON object, usually built with `serde_json::json!`.
+pub fn record(level: Level, event: &'static str, fields: Value) {
+    if let Some(logger) = logger() {
+        logger.record(level, event, fields);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_logger_writes_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(LOG_FILE);
+        let logger = Logger::open(&path).unwrap();
+
+        logger.record(
+            Level::Info,
+            "response",
+            json!({ "url": "http://localhost/api/latest", "status": 200 }),
+        );
+        logger.record(Level::Debug, "key", json!({ "key": "j" }));
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let lines: Vec<Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["event"], "response");
+        assert_eq!(lines[0]["level"], "info");
+        assert_eq!(lines[0]["status"], 200);
+        assert_eq!(lines[1]["key"], "j");
+    }
+
+    #[test]
+    fn test_logger_keeps_recent_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let logger = Logger::open(dir.path().join(LOG_FILE)).unwrap();
+
+        for i in 0..MAX_RECENT + 10 {
+            logger.record(Level::Debug, "key", json!({ "n": i }));
+        }
+
+        let recent = logger.recent(3);
+        assert_eq!(recent.len(), 3);
+        assert_eq!(recent[2].fields["n"], MAX_RECENT + 9);
+        assert_eq!(logger.recent(usize::MAX).len(), MAX_RECENT);
+    }
+
+    #[test]
+    fn test_logger_rotates_full_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOG_FILE);
+        let logger = Logger::with_max_bytes(&path, 200).unwrap();
+
+        for i in 0..10 {
+            logger.record(Level::Debug, "key", json!({ "n": i }));
+        }
+
+        let current = fs::read_to_string(&path).unwrap();
+        let previous = fs::read_to_string(dir.path().join("debug.log.1")).unwrap();
+        assert!(current.len() <= 200 && previous.len() <= 200);
+        let last: Value = serde_json::from_str(current.lines().last().unwrap()).unwrap();
+        assert_eq!(last["n"], 9);
+        assert_eq!(logger.recent(usize::MAX).len(), 10);
+    }
+
+    #[test]
+    fn test_entry_summary() {
+        let entry = Entry {
+            time: Utc::now(),
+            level: Level::Warn,
+            event: "decode_error",
+            fields: json!({ "url": "http://x/api", "status": 200 })
+                .as_object()
+                .unwrap()
+                .clone(),
+        };
+
+        let summary = entry.summary();
+        assert!(summary.ends_with(" warn  decode_error status=200 url=http://x/api"));
+    }
+}
@@ -1,27 +1,93 @@
 use anyhow::Result;
-use clap::Parser;
+use clap::CommandFactory;
 use crossterm::{
     event::{
-        self as crossterm_event, DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind,
+        DisableMouseCapture, EnableMouseCapture, Event, EventStream, KeyEventKind, KeyModifiers,
     },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{backend::CrosstermBackend, Terminal};
-use std::io;
+use futures::StreamExt;
+use ratatui::{backend::CrosstermBackend, layout::Rect, Terminal};
+use std::io::{self, IsTerminal};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{Instant, MissedTickBehavior};
 
 mod api;
 mod app;
+mod bookmarks;
+mod cache;
+mod commands;
 mod config;
+mod digest;
+mod email;
 mod event;
+mod facets;
+mod feed;
+mod fetch;
+mod json_file;
+mod keymap;
+mod logging;
+mod markdown;
+mod read_state;
+mod search;
+#[cfg(test)]
+mod test_support;
+mod theme;
 mod ui;
 
-use app::App;
-use config::Config;
+use api::{ApiClient, DateRange};
+use app::{App, Tab, ViewMode};
+use bookmarks::Bookmarks;
+use cache::ArticleCache;
+use config::{Command, Config, ConfigCommand, ListArgs};
+use keymap::KeyBinding;
+use logging::Level;
+use read_state::ReadState;
+use serde_json::json;
+
+/// Frame interval while a fetch is in flight or an article is being read
+const TICK_RATE: Duration = Duration::from_millis(100);
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let config = Config::parse();
+    let config = Config::load()?;
+
+    // Validate before entering the alternate screen so the message stays visible
+    let date_range = match config.date_range() {
+        Ok(range) => range,
+        Err(e) => Config::command()
+            .error(clap::error::ErrorKind::ValueValidation, e)
+            .exit(),
+    };
+
+    if config.debug {
+        if let Some(path) = config.log_file.clone().or_else(logging::default_path) {
+            logging::init(path)?;
+        }
+        logging::record(
+            Level::Info,
+            "startup",
+            json!({
+                "version": env!("CARGO_PKG_VERSION"),
+                "api_url": config.api_url,
+                "command": format!("{:?}", config.command),
+            }),
+        );
+    }
+
+    match &config.command {
+        Some(Command::List(args)) => return commands::list(&config, args).await,
+        Some(Command::Digest(args)) => return digest::run(&config, args).await,
+        Some(Command::Feed(args)) => return feed::run(&config, args).await,
+        Some(Command::Config(ConfigCommand::Show)) => return commands::show_config(&config),
+        // Piped or redirected: print a plain list instead of drawing a TUI
+        None if !io::stdout().is_terminal() => {
+            return commands::list(&config, &ListArgs::default()).await
+        }
+        None => {}
+    }
 
     // Setup terminal
     enable_raw_mode()?;
@@ -31,7 +97,7 @@ async fn main() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Run app
-    let result = run_app(&mut terminal, config).await;
+    let result = run_app(&mut terminal, config, date_range).await;
 
     // Restore terminal
     disable_raw_mode()?;
@@ -53,34 +119,144 @@ async fn main() -> Result<()> {
 async fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     config: Config,
+    date_range: Option<DateRange>,
 ) -> Result<()> {
     let mut app = App::new(&config.api_url, config.limit);
+    app.client = ApiClient::new(&config.api_url).with_retry_policy(config.retry_policy());
+    app.cache = ArticleCache::default_path().map(ArticleCache::open);
+    // Broken read state or bookmarks files are reported and left alone rather
+    // than overwritten
+    let mut load_errors = Vec::new();
+    match ReadState::default_path().map(ReadState::open) {
+        Some(Ok(read)) => app.read = read,
+        Some(Err(e)) => load_errors.push(format!("{:#}", e)),
+        None => {}
+    }
+    match Bookmarks::default_path().map(Bookmarks::open) {
+        Some(Ok(bookmarks)) => app.bookmarks = bookmarks,
+        Some(Err(e)) => load_errors.push(format!("{:#}", e)),
+        None => {}
+    }
+    if !load_errors.is_empty() {
+        app.last_error = Some(load_errors.join("\n"));
+    }
+    app.offline = config.offline;
+    app.keymap = config.keymap.clone();
+    app.theme = config.theme.theme();
+    app.filters = config.filters.clone();
+    app.default_filters = config.filters.clone();
+    if let Some(range) = date_range {
+        app.tab = Tab::Timeline;
+        app.mode = ViewMode::Range(range);
+    } else if config.timeline {
+        app.tab = Tab::Timeline;
+        app.mode = ViewMode::Timeline;
+    }
+    let size = terminal.size()?;
+    let mut area = Rect::new(0, 0, size.width, size.height);
+    app.set_terminal_width(area.width);
+
+    let (fetch_tx, mut fetch_rx) = mpsc::unbounded_channel();
+    let mut events = EventStream::new();
+    let mut ticker = tokio::time::interval(TICK_RATE);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
-    // Initial load
-    app.load_initial().await?;
+    // Background polling for new articles, first check one period after startup
+    let mut auto_refresh = config.refresh_interval().map(|period| {
+        let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        interval
+    });
+
+    // Show cached articles right away; fresh ones replace them when the fetch lands
+    app.load_cached();
+    app.load_initial();
+
+    // Only redraw after something visible changed
+    let mut dirty = true;
 
     // Main event loop
     loop {
+        // Check refresh
+        if app.needs_refresh {
+            app.needs_refresh = false;
+            app.refresh();
+        }
+
+        // Infinite scroll: fetch the next page near the end of the list
+        if app.should_load_more() {
+            app.load_more();
+        }
+
+        // Start queued fetches in the background
+        for job in app.take_fetch_jobs() {
+            fetch::spawn(app.client.clone(), job, fetch_tx.clone());
+            dirty = true;
+        }
+
         // Render
-        terminal.draw(|f| ui::render(f, &mut app))?;
+        if dirty {
+            app.sync_detail();
+            app.clamp_detail_scroll(ui::max_detail_scroll(&app, area));
+            terminal.draw(|f| ui::render(f, &mut app))?;
+            dirty = false;
+        }
 
         // Check if should quit
         if app.should_quit {
             break;
         }
 
-        // Check refresh
-        if app.needs_refresh {
-            app.needs_refresh = false;
-            app.refresh().await?;
-        }
-
-        // Handle events
-        if crossterm_event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = crossterm_event::read()? {
-                if key.kind == KeyEventKind::Press {
+        tokio::select! {
+            event = events.next() => match event {
+                Some(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => {
+                    let binding = KeyBinding {
+                        code: key.code,
+                        ctrl: key.modifiers.contains(KeyModifiers::CONTROL),
+                    };
+                    // Keys typed into a prompt are text, not commands; keep them out of the log
+                    if !app.is_prompt_open() {
+                        logging::record(Level::Debug, "key", json!({ "key": binding.to_string() }));
+                    }
                     event::handle_key_event(&mut app, key);
+                    dirty = true;
                 }
+                Some(Ok(Event::Resize(width, height))) => {
+                    area = Rect::new(0, 0, width, height);
+                    app.set_terminal_width(width);
+                    dirty = true;
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(e.into()),
+                None => break,
+            },
+            Some(result) = fetch_rx.recv() => {
+                logging::record(
+                    Level::Debug,
+                    "fetch_done",
+                    json!({
+                        "kind": format!("{:?}", result.kind),
+                        "generation": result.generation,
+                        "stale": result.generation != app.generation(),
+                        "articles": result.result.as_ref().map_or(0, |page| page.articles.len()),
+                    }),
+                );
+                app.handle_fetch_result(result);
+                dirty = true;
+            }
+            // Redraw the log pane as entries come in
+            _ = async { logging::logger().expect("guarded").added().await },
+                if app.show_log && logging::logger().is_some() => {
+                dirty = true;
+            }
+            _ = async { auto_refresh.as_mut().expect("guarded").tick().await },
+                if auto_refresh.is_some() => {
+                app.poll_for_new();
+            }
+            // Spinner, retry countdown and read timer only need frames while active
+            _ = ticker.tick(), if app.needs_tick() => {
+                app.on_tick();
+                dirty = true;
             }
         }
     }
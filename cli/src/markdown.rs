@@ -0,0 +1,464 @@
+use crate::search;
+use crate::theme::Theme;
+use crate::ui::{char_width, display_width};
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Render Markdown `text` as styled lines no wider than `width` columns,
+/// with matches of the search `query` highlighted.
+///
+/// Covers what article summaries use: paragraphs, headings, emphasis, inline
+/// and fenced code, links, bullet and numbered lists, and block quotes. Single
+/// line breaks are kept, as summaries are often written one point per line.
+pub fn render(text: &str, width: usize, query: &str, theme: &Theme) -> Vec<Line<'static>> {
+    let mut writer = Writer::new(width, query, theme);
+    for event in Parser::new_ext(text, Options::ENABLE_STRIKETHROUGH) {
+        writer.event(event);
+    }
+    writer.flush();
+    writer.lines
+}
+
+struct Writer<'a> {
+    width: usize,
+    query: &'a str,
+    theme: &'a Theme,
+    lines: Vec<Line<'static>>,
+    /// Styled text of the line being built, before wrapping
+    segments: Vec<(String, Style)>,
+    styles: Vec<Style>,
+    /// Next number of each open list, `None` for bullet lists
+    lists: Vec<Option<u64>>,
+    /// Marker of the list item whose first line hasn't been written yet
+    marker: Option<String>,
+    quote_depth: usize,
+    link: Option<String>,
+    in_code_block: bool,
+    /// A blank line goes before the next block
+    gap: bool,
+}
+
+impl<'a> Writer<'a> {
+    fn new(width: usize, query: &'a str, theme: &'a Theme) -> Self {
+        Self {
+            width,
+            query,
+            theme,
+            lines: Vec::new(),
+            segments: Vec::new(),
+            styles: vec![Style::default().fg(theme.text)],
+            lists: Vec::new(),
+            marker: None,
+            quote_depth: 0,
+            link: None,
+            in_code_block: false,
+            gap: false,
+        }
+    }
+
+    fn style(&self) -> Style {
+        *self.styles.last().expect("base style")
+    }
+
+    fn push_style(&mut self, f: impl FnOnce(Style) -> Style) {
+        self.styles.push(f(self.style()));
+    }
+
+    fn pop_style(&mut self) {
+        if self.styles.len() > 1 {
+            self.styles.pop();
+        }
+    }
+
+    fn code_style(&self) -> Style {
+        self.style().fg(self.theme.warning)
+    }
+
+    fn text(&mut self, text: &str, style: Style) {
+        if !text.is_empty() {
+            self.segments.push((text.to_string(), style));
+        }
+    }
+
+    /// Blank line before a top-level block, after the one before it
+    fn start_block(&mut self) {
+        if self.lists.is_empty() && self.gap && !self.lines.is_empty() {
+            self.lines.push(Line::from(""));
+        }
+        self.gap = false;
+    }
+
+    fn end_block(&mut self) {
+        self.flush();
+        if self.lists.is_empty() {
+            self.gap = true;
+        }
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) if self.in_code_block => {
+                let style = self.code_style();
+                for (i, line) in text.trim_end_matches('\n').split('\n').enumerate() {
+                    if i > 0 {
+                        self.flush();
+                    }
+                    self.text(line, style);
+                }
+            }
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                self.text(&text, self.style())
+            }
+            Event::Code(code) => self.text(&code, self.code_style()),
+            Event::SoftBreak | Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.start_block();
+                let rule = "─".repeat(self.width.clamp(1, 40));
+                self.lines.push(Line::from(Span::styled(
+                    rule,
+                    Style::default().fg(self.theme.muted),
+                )));
+                self.gap = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.start_block(),
+            Tag::Heading { .. } => {
+                self.start_block();
+                let strong = self.theme.strong;
+                self.push_style(|s| s.fg(strong).add_modifier(Modifier::BOLD));
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                self.quote_depth += 1;
+                let muted = self.theme.muted;
+                self.push_style(|s| s.fg(muted).add_modifier(Modifier::ITALIC));
+            }
+            Tag::CodeBlock(kind) => {
+                self.start_block();
+                self.in_code_block = true;
+                if let CodeBlockKind::Fenced(lang) = kind {
+                    if !lang.is_empty() {
+                        self.text(&lang, Style::default().fg(self.theme.muted));
+                        self.flush();
+                    }
+                }
+            }
+            Tag::List(start) => {
+                // Text of the enclosing item goes on its own line
+                self.flush();
+                self.start_block();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}. ", *n - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                let nesting = "  ".repeat(self.lists.len().saturating_sub(1));
+                self.marker = Some(format!("{}{}", nesting, marker));
+            }
+            Tag::Emphasis => self.push_style(|s| s.add_modifier(Modifier::ITALIC)),
+            Tag::Strong => self.push_style(|s| s.add_modifier(Modifier::BOLD)),
+            Tag::Strikethrough => self.push_style(|s| s.add_modifier(Modifier::CROSSED_OUT)),
+            Tag::Link { dest_url, .. } => {
+                self.link = Some(dest_url.to_string());
+                let link = self.theme.link;
+                self.push_style(|s| s.fg(link).add_modifier(Modifier::UNDERLINED));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.end_block(),
+            TagEnd::Heading(_) => {
+                self.pop_style();
+                self.end_block();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+                self.pop_style();
+                self.end_block();
+            }
+            TagEnd::CodeBlock => {
+                self.in_code_block = false;
+                self.end_block();
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.gap = true;
+                }
+            }
+            TagEnd::Item => self.flush(),
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => self.pop_style(),
+            TagEnd::Link => {
+                self.pop_style();
+                // Autolinks already show the URL
+                if let Some(url) = self.link.take() {
+                    let text: String = self.segments.iter().map(|(t, _)| t.as_str()).collect();
+                    if !text.ends_with(&url) {
+                        self.text(
+                            &format!(" ({})", url),
+                            Style::default().fg(self.theme.muted),
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Wrap the pending segments into lines. The first line starts with the
+    /// list marker, if any; the rest are indented to line up after it.
+    fn flush(&mut self) {
+        let marker = self.marker.take();
+        if self.segments.is_empty() && marker.is_none() {
+            return;
+        }
+        let segments = highlight(std::mem::take(&mut self.segments), self.query, self.theme);
+
+        let quote = "│ ".repeat(self.quote_depth);
+        let marker = marker.unwrap_or_default();
+        let hanging = " ".repeat(display_width(&marker));
+        let available = self
+            .width
+            .saturating_sub(display_width(&quote) + display_width(&marker))
+            .max(10);
+
+        let muted = Style::default().fg(self.theme.muted);
+        let strong = Style::default().fg(self.theme.strong);
+        for (i, spans) in wrap(&segments, available).into_iter().enumerate() {
+            let mut line = Vec::new();
+            if !quote.is_empty() {
+                line.push(Span::styled(quote.clone(), muted));
+            }
+            if i == 0 {
+                if !marker.is_empty() {
+                    line.push(Span::styled(marker.clone(), strong));
+                }
+            } else if !hanging.is_empty() {
+                line.push(Span::raw(hanging.clone()));
+            }
+            line.extend(spans);
+            self.lines.push(Line::from(line));
+        }
+    }
+}
+
+/// Split `segments` so matches of `query` across the whole line get the
+/// highlight style
+pub fn highlight(
+    segments: Vec<(String, Style)>,
+    query: &str,
+    theme: &Theme,
+) -> Vec<(String, Style)> {
+    let text: String = segments.iter().map(|(t, _)| t.as_str()).collect();
+    let matches = search::find_matches(&text, query);
+    if matches.is_empty() {
+        return segments;
+    }
+
+    let mut out = Vec::new();
+    let mut offset = 0;
+    for (segment, style) in segments {
+        let (start, end) = (offset, offset + segment.len());
+        offset = end;
+        let mut cut = start;
+        for range in matches.iter().filter(|r| r.start < end && r.end > start) {
+            let (from, to) = (range.start.max(start), range.end.min(end));
+            if from > cut {
+                out.push((text[cut..from].to_string(), style));
+            }
+            out.push((
+                text[from..to].to_string(),
+                style.fg(theme.inverse).bg(theme.warning),
+            ));
+            cut = to;
+        }
+        if cut < end {
+            out.push((text[cut..end].to_string(), style));
+        }
+    }
+    out
+}
+
+/// Break styled text into lines of at most `width` columns, counting wide
+/// characters as 2, the same way the plain-text wrapper does
+pub fn wrap(segments: &[(String, Style)], width: usize) -> Vec<Vec<Span<'static>>> {
+    let mut lines = vec![];
+    let mut line: Vec<Span<'static>> = vec![];
+    let mut current = String::new();
+    let mut line_width = 0;
+
+    for (text, style) in segments {
+        for ch in text.chars() {
+            let w = char_width(ch);
+            if line_width + w > width && line_width > 0 {
+                if !current.is_empty() {
+                    line.push(Span::styled(std::mem::take(&mut current), *style));
+                }
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            current.push(ch);
+            line_width += w;
+        }
+        if !current.is_empty() {
+            line.push(Span::styled(std::mem::take(&mut current), *style));
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(lines: &[Line<'static>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    fn span<'a>(lines: &'a [Line<'static>], text: &str) -> &'a Span<'static> {
+        lines
+            .iter()
+            .flat_map(|line| &line.spans)
+            .find(|s| s.content == text)
+            .unwrap_or_else(|| panic!("no span {:?}", text))
+    }
+
+    #[test]
+    fn test_inline_styles() {
+        let theme = Theme::default();
+        let lines = render("**粗体** and *italic* with `code`", 80, "", &theme);
+        assert_eq!(plain(&lines), vec!["粗体 and italic with code"]);
+        assert!(span(&lines, "粗体")
+            .style
+            .add_modifier
+            .contains(Modifier::BOLD));
+        assert!(span(&lines, "italic")
+            .style
+            .add_modifier
+            .contains(Modifier::ITALIC));
+        assert_eq!(span(&lines, "code").style.fg, Some(theme.warning));
+    }
+
+    #[test]
+    fn test_links() {
+        let theme = Theme::default();
+        let lines = render(
+            "See [the post](https://example.com/a) or <https://example.com/b>",
+            80,
+            "",
+            &theme,
+        );
+        assert_eq!(
+            plain(&lines),
+            vec!["See the post (https://example.com/a) or https://example.com/b"]
+        );
+        let link = span(&lines, "the post");
+        assert_eq!(link.style.fg, Some(theme.link));
+        assert!(link.style.add_modifier.contains(Modifier::UNDERLINED));
+    }
+
+    #[test]
+    fn test_lists_and_paragraphs() {
+        let theme = Theme::default();
+        let lines = render(
+            "要点：\n\n- 第一点\n- 第二点\n  - 细节\n\n1. one\n2. two\n\n结论",
+            80,
+            "",
+            &theme,
+        );
+        assert_eq!(
+            plain(&lines),
+            vec![
+                "要点：",
+                "",
+                "• 第一点",
+                "• 第二点",
+                "  • 细节",
+                "",
+                "1. one",
+                "2. two",
+                "",
+                "结论",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_breaks_are_kept() {
+        let lines = render("第一行\n第二行", 80, "", &Theme::default());
+        assert_eq!(plain(&lines), vec!["第一行", "第二行"]);
+    }
+
+    #[test]
+    fn test_wrapping_counts_wide_chars() {
+        let lines = render("- 这是一个很长的中文列表项目", 14, "", &Theme::default());
+        // 12 columns left after the marker: 6 CJK characters per line
+        assert_eq!(
+            plain(&lines),
+            vec!["• 这是一个很长", "  的中文列表项", "  目"]
+        );
+    }
+
+    #[test]
+    fn test_highlight_search_matches() {
+        let theme = Theme::default();
+        let style = Style::default().fg(theme.text);
+        let segments = highlight(vec![("Rust 与 rust".to_string(), style)], "RUST", &theme);
+
+        let text: Vec<&str> = segments.iter().map(|(text, _)| text.as_str()).collect();
+        assert_eq!(text, vec!["Rust", " 与 ", "rust"]);
+        assert_eq!(segments[0].1.bg, Some(theme.warning));
+        assert_eq!(segments[1].1, style);
+
+        let segments = highlight(vec![("Rust".to_string(), style)], "", &theme);
+        assert_eq!(segments, vec![("Rust".to_string(), style)]);
+    }
+
+    #[test]
+    fn test_search_highlight_spans_wrap() {
+        let theme = Theme::default();
+        let lines = render("abcdefghijklmnop", 10, "ijkl", &theme);
+        assert_eq!(plain(&lines), vec!["abcdefghij", "klmnop"]);
+        assert_eq!(span(&lines, "ij").style.bg, Some(theme.warning));
+        assert_eq!(span(&lines, "kl").style.bg, Some(theme.warning));
+    }
+
+    #[test]
+    fn test_search_highlight_spans_styles() {
+        let theme = Theme::default();
+        let lines = render("**Ru**st news", 80, "rust", &theme);
+        assert_eq!(plain(&lines), vec!["Rust news"]);
+        let bold = span(&lines, "Ru");
+        assert_eq!(bold.style.bg, Some(theme.warning));
+        assert!(bold.style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(span(&lines, "st").style.bg, Some(theme.warning));
+        assert_eq!(span(&lines, " news").style.bg, None);
+    }
+}
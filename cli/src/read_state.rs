@@ -0,0 +1,146 @@
+use crate::json_file;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Entries read longest ago are forgotten beyond this many
+const MAX_READ_ENTRIES: usize = 10_000;
+
+const STATE_FILE: &str = "read.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StateFile {
+    /// URL -> when it was marked read
+    read: HashMap<String, DateTime<Utc>>,
+}
+
+/// Which articles have been read, keyed by URL. Persisted as JSON under the
+/// user's state directory; without a path it only lives in memory.
+#[derive(Debug, Default)]
+pub struct ReadState {
+    path: Option<PathBuf>,
+    read: HashMap<String, DateTime<Utc>>,
+}
+
+impl ReadState {
+    /// Default location: `$XDG_STATE_HOME/ptlk/read.json`, or the local data
+    /// directory on platforms without a state directory
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::state_dir()
+            .or_else(dirs::data_local_dir)
+            .map(|dir| dir.join("ptlk").join(STATE_FILE))
+    }
+
+    /// Load the state at `path`. A missing file yields an empty state, a
+    /// corrupt one an error.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let read = json_file::load::<StateFile>(&path)?
+            .unwrap_or_default()
+            .read;
+
+        Ok(Self {
+            path: Some(path),
+            read,
+        })
+    }
+
+    pub fn is_read(&self, url: &str) -> bool {
+        self.read.contains_key(url)
+    }
+
+    /// Mark `urls` read. Returns whether anything changed.
+    pub fn mark_read<'a>(&mut self, urls: impl IntoIterator<Item = &'a str>) -> bool {
+        let now = Utc::now();
+        let mut changed = false;
+        for url in urls {
+            if !self.read.contains_key(url) {
+                self.read.insert(url.to_string(), now);
+                changed = true;
+            }
+        }
+        self.prune();
+        changed
+    }
+
+    /// Returns whether `url` was read
+    pub fn mark_unread(&mut self, url: &str) -> bool {
+        self.read.remove(url).is_some()
+    }
+
+    fn prune(&mut self) {
+        if self.read.len() <= MAX_READ_ENTRIES {
+            return;
+        }
+
+        let mut by_age: Vec<(DateTime<Utc>, String)> = self
+            .read
+            .iter()
+            .map(|(url, at)| (*at, url.clone()))
+            .collect();
+        by_age.sort();
+
+        let excess = self.read.len() - MAX_READ_ENTRIES;
+        for (_, url) in by_age.into_iter().take(excess) {
+            self.read.remove(&url);
+        }
+    }
+
+    /// Write the state to disk, replacing the file atomically. A no-op for
+    /// in-memory state.
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let file = StateFile {
+            read: self.read.clone(),
+        };
+        json_file::write_json_atomic(path, &file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_read_state_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(STATE_FILE);
+
+        let mut state = ReadState::open(&path).unwrap();
+        assert!(!state.is_read("https://example.com/1"));
+
+        assert!(state.mark_read(["https://example.com/1", "https://example.com/2"]));
+        assert!(!state.mark_read(["https://example.com/1"]));
+        assert!(state.mark_unread("https://example.com/2"));
+        assert!(!state.mark_unread("https://example.com/2"));
+        state.save().unwrap();
+
+        let state = ReadState::open(&path).unwrap();
+        assert!(state.is_read("https://example.com/1"));
+        assert!(!state.is_read("https://example.com/2"));
+    }
+
+    #[test]
+    fn test_corrupt_read_state_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(STATE_FILE);
+        fs::write(&path, "not json").unwrap();
+
+        let err = ReadState::open(&path).unwrap_err();
+        assert!(err.to_string().starts_with("Failed to parse"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "not json");
+    }
+
+    #[test]
+    fn test_in_memory_state_saves_nothing() {
+        let mut state = ReadState::default();
+        state.mark_read(["https://example.com/1"]);
+        state.save().unwrap();
+        assert!(state.is_read("https://example.com/1"));
+    }
+}
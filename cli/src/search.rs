@@ -0,0 +1,87 @@
+use crate::api::Article;
+use std::ops::Range;
+
+/// Case-fold one character for matching: lowercase, with fullwidth ASCII
+/// (`Ａ`, `１`, ...) treated as its halfwidth form, as typed by CJK input methods
+fn fold(ch: char) -> char {
+    let ch = match ch as u32 {
+        0xFF01..=0xFF5E => char::from_u32(ch as u32 - 0xFEE0).unwrap_or(ch),
+        0x3000 => ' ',
+        _ => ch,
+    };
+    ch.to_lowercase().next().unwrap_or(ch)
+}
+
+/// Byte ranges of the non-overlapping, case-insensitive matches of `query` in `text`
+pub fn find_matches(text: &str, query: &str) -> Vec<Range<usize>> {
+    let needle: Vec<char> = query.chars().map(fold).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let haystack: Vec<(usize, char)> = text.char_indices().map(|(i, c)| (i, fold(c))).collect();
+    let mut matches = Vec::new();
+    let mut start = 0;
+    while start + needle.len() <= haystack.len() {
+        let window = &haystack[start..start + needle.len()];
+        if window.iter().map(|(_, c)| c).eq(needle.iter()) {
+            let end = haystack
+                .get(start + needle.len())
+                .map_or(text.len(), |(i, _)| *i);
+            matches.push(haystack[start].0..end);
+            start += needle.len();
+        } else {
+            start += 1;
+        }
+    }
+    matches
+}
+
+/// Whether `query` occurs in the article's title, summary or source
+pub fn article_matches(article: &Article, query: &str) -> bool {
+    [&article.title, &article.summary, &article.source]
+        .into_iter()
+        .any(|field| !find_matches(field, query).is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    #[test]
+    fn test_find_matches_case_insensitive() {
+        assert_eq!(find_matches("Rust and rust", "RUST"), vec![0..4, 9..13]);
+        assert_eq!(find_matches("aaaa", "aa"), vec![0..2, 2..4]);
+        assert!(find_matches("Rust", "").is_empty());
+        assert!(find_matches("Rust", "go").is_empty());
+    }
+
+    #[test]
+    fn test_find_matches_cjk() {
+        let text = "大模型 GPT-5 发布";
+        let ranges = find_matches(text, "模型");
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(&text[ranges[0].clone()], "模型");
+
+        // Fullwidth input matches halfwidth text and vice versa
+        let ranges = find_matches(text, "ｇｐｔ");
+        assert_eq!(&text[ranges[0].clone()], "GPT");
+        let ranges = find_matches("ＯｐｅｎＡＩ", "openai");
+        assert_eq!(ranges, vec![0.."ＯｐｅｎＡＩ".len()]);
+    }
+
+    #[test]
+    fn test_article_matches() {
+        let article = Article {
+            title: "Title".to_string(),
+            summary: "量子计算".to_string(),
+            source: "Hacker News".to_string(),
+            ..test_support::article("https://example.com/needle")
+        };
+
+        assert!(article_matches(&article, "hacker"));
+        assert!(article_matches(&article, "量子"));
+        assert!(!article_matches(&article, "needle"));
+    }
+}
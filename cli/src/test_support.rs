@@ -0,0 +1,17 @@
+//! Fixtures shared by the unit tests
+
+use crate::api::Article;
+
+/// An article at `url` with placeholder values; override fields with struct
+/// update syntax
+pub fn article(url: &str) -> Article {
+    Article {
+        title: format!("Title {}", url),
+        url: url.to_string(),
+        summary: "Summary".to_string(),
+        tag: "Tech".to_string(),
+        source: "Source".to_string(),
+        published_at: "2026-10-15T08:00:00+00:00".to_string(),
+        briefing_date: "2026-10-15".to_string(),
+    }
+}
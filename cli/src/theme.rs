@@ -0,0 +1,73 @@
+use clap::ValueEnum;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+/// Color scheme, chosen with `--theme` or `theme` in the config file
+#[derive(ValueEnum, Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeName {
+    /// Light text for dark terminal backgrounds
+    #[default]
+    Dark,
+    /// Dark text for light terminal backgrounds
+    Light,
+}
+
+impl ThemeName {
+    pub fn theme(self) -> Theme {
+        match self {
+            ThemeName::Dark => Theme::DARK,
+            ThemeName::Light => Theme::LIGHT,
+        }
+    }
+}
+
+/// Colors by role, so the UI never names a color directly
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Key hints, popup text and other emphasized text
+    pub strong: Color,
+    /// Titles, summaries, tags and borders
+    pub text: Color,
+    /// Metadata, section headers and placeholders
+    pub muted: Color,
+    pub warning: Color,
+    pub error: Color,
+    pub link: Color,
+    /// Text drawn on a `warning` background
+    pub inverse: Color,
+    pub selected_fg: Color,
+    pub selected_bg: Color,
+}
+
+impl Theme {
+    pub const DARK: Theme = Theme {
+        strong: Color::White,
+        text: Color::Gray,
+        muted: Color::DarkGray,
+        warning: Color::Yellow,
+        error: Color::Red,
+        link: Color::Blue,
+        inverse: Color::Black,
+        selected_fg: Color::Black,
+        selected_bg: Color::Gray,
+    };
+
+    pub const LIGHT: Theme = Theme {
+        strong: Color::Black,
+        text: Color::Rgb(0x1f, 0x23, 0x28),
+        muted: Color::Rgb(0x6e, 0x77, 0x81),
+        warning: Color::Rgb(0x9a, 0x67, 0x00),
+        error: Color::Rgb(0xcf, 0x22, 0x2e),
+        link: Color::Rgb(0x09, 0x69, 0xda),
+        inverse: Color::White,
+        selected_fg: Color::Black,
+        selected_bg: Color::Rgb(0xd0, 0xd7, 0xde),
+    };
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::DARK
+    }
+}
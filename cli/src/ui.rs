@@ -1,9 +1,15 @@
-use crate::app::{App, LoadingState};
+use crate::api::{ApiError, Article};
+use crate::app::{App, LoadingState, Prompt, Tab, ViewMode};
+use crate::facets::{FacetPicker, Selection};
+use crate::keymap::{Action, Keymap};
+use crate::logging::{self, Level};
+use crate::markdown;
+use crate::theme::Theme;
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Tabs, Wrap},
     Frame,
 };
 
@@ -16,194 +22,572 @@ const POTLUCK_TITLE: &str = r#"
 ╚═╝      ╚═════╝    ╚═╝   ╚══════╝ ╚═════╝  ╚═════╝╚═╝  ╚═╝
 "#;
 
+/// Rows a section header adds above its first article
+const SECTION_HEADER_ROWS: usize = 2;
+
+/// Rows taken by the debug log pane, borders included
+const LOG_PANE_HEIGHT: u16 = 10;
+
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
 /// Render the UI
 pub fn render(f: &mut Frame, app: &mut App) {
-    let chunks = Layout::default()
-        .constraints([
-            Constraint::Length(8), // Title
-            Constraint::Min(0),    // Content
-            Constraint::Length(1), // Footer
-        ])
-        .split(f.area());
-
-    render_title(f, chunks[0]);
-    render_content(f, chunks[1], app);
-    render_footer(f, chunks[2]);
+    let [title_area, tab_area, content_area, log_area, footer_area] = frame_areas(app, f.area());
+
+    render_title(f, title_area, app);
+    render_tab_bar(f, tab_area, app);
+    render_content(f, content_area, app);
+    if app.show_log {
+        render_log_pane(f, log_area, &app.theme);
+    }
+    render_footer(f, footer_area, app);
+
+    if let Some(prompt) = &app.date_prompt {
+        render_date_prompt(f, prompt, &app.theme);
+    }
+    if let Some(picker) = app.facet_picker.as_mut() {
+        render_facet_picker(f, picker, &app.theme);
+    }
 
     // Render error popup if exists
     if let Some(error) = &app.last_error {
-        render_error_popup(f, error);
+        render_error_popup(f, error, &app.theme);
     }
 }
 
-fn render_title(f: &mut Frame, area: Rect) {
+fn render_title(f: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
     let title = Paragraph::new(POTLUCK_TITLE)
-        .style(
-            Style::default()
-                .fg(Color::Gray)
-                .add_modifier(Modifier::BOLD),
-        )
+        .style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
         .alignment(ratatui::layout::Alignment::Left);
     f.render_widget(title, area);
+
+    if app.loading_state == LoadingState::Loaded && !app.articles.is_empty() {
+        let status_area = Rect {
+            y: area.bottom().saturating_sub(1),
+            height: area.height.min(1),
+            ..area
+        };
+        let mut status = format!("{} unread ", app.unread_count());
+        if !app.bookmarks.is_empty() {
+            status.push_str(&format!("· {} saved ", app.bookmarks.len()));
+        }
+        let unread = Paragraph::new(Span::styled(status, Style::default().fg(theme.muted)))
+            .alignment(ratatui::layout::Alignment::Right);
+        f.render_widget(unread, status_area);
+    }
+}
+
+/// `1 Latest  2 Timeline  3 Saved  4 Search`, with the active tab highlighted
+fn render_tab_bar(f: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let titles = Tab::ALL.iter().enumerate().map(|(i, tab)| {
+        let label = match tab {
+            Tab::Search if !app.search_results_query.is_empty() => {
+                format!("{} Search: {}", i + 1, app.search_results_query)
+            }
+            _ => format!("{} {}", i + 1, tab.label()),
+        };
+        Line::from(label)
+    });
+
+    let tabs = Tabs::new(titles)
+        .select(app.tab.index())
+        .style(Style::default().fg(theme.muted))
+        .highlight_style(
+            Style::default()
+                .fg(theme.strong)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        )
+        .divider(" ");
+    f.render_widget(tabs, area);
+}
+
+/// Title, tab bar, content, debug log and footer areas of the frame; the log
+/// area is empty unless the log pane is shown
+fn frame_areas(app: &App, area: Rect) -> [Rect; 5] {
+    let [title_area, tab_area, body_area, footer_area] = Layout::vertical([
+        Constraint::Length(8),
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+    let log_height = if app.show_log { LOG_PANE_HEIGHT } else { 0 };
+    let [content_area, log_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(log_height)]).areas(body_area);
+    [title_area, tab_area, content_area, log_area, footer_area]
+}
+
+/// Banner, list and detail pane areas of the loaded content; the banner is
+/// empty without new articles and the detail pane outside the split layout
+fn loaded_areas(app: &App, area: Rect) -> [Rect; 3] {
+    let banner_height = if app.new_articles > 0 { 1 } else { 0 };
+    let [banner_area, area] =
+        Layout::vertical([Constraint::Length(banner_height), Constraint::Min(0)]).areas(area);
+    if !app.split_active {
+        return [banner_area, area, Rect::default()];
+    }
+    let [list_area, detail_area] = Layout::horizontal([
+        Constraint::Percentage(app.split_percent),
+        Constraint::Min(0),
+    ])
+    .areas(area);
+    [banner_area, list_area, detail_area]
+}
+
+/// How far the detail pane can scroll in a frame of `area` before the last
+/// line of the article leaves the top
+pub fn max_detail_scroll(app: &App, area: Rect) -> u16 {
+    let [_, _, content_area, _, _] = frame_areas(app, area);
+    let [_, _, detail_area] = loaded_areas(app, content_area);
+    let Some(article) = app.selected_article().filter(|_| app.split_active) else {
+        return 0;
+    };
+    let inner = detail_inner(detail_area);
+    let lines = detail_lines(article, inner.width as usize, app);
+    (lines.len() as u16).saturating_sub(inner.height)
 }
 
 fn render_content(f: &mut Frame, area: Rect, app: &mut App) {
+    let theme = &app.theme;
     match &app.loading_state {
         LoadingState::Loading => {
-            render_loading(f, area);
+            render_loading(f, area, app.tick, theme);
         }
-        LoadingState::Error(msg) => {
-            render_error(f, area, msg);
+        LoadingState::Error(error) => {
+            render_error(f, area, error, theme, &app.keymap);
         }
         LoadingState::Loaded => {
-            render_article_list(f, area, app);
+            let [banner_area, list_area, detail_area] = loaded_areas(app, area);
+            if app.new_articles > 0 {
+                render_new_articles_banner(f, banner_area, app.new_articles, theme, &app.keymap);
+            }
+            render_article_list(f, list_area, app);
+            if app.split_active {
+                render_detail_pane(f, detail_area, app);
+            }
         }
     }
 }
 
-fn render_loading(f: &mut Frame, area: Rect) {
-    let loading = Paragraph::new("Loading articles...").style(Style::default().fg(Color::Gray));
+fn spinner(tick: usize) -> &'static str {
+    SPINNER_FRAMES[tick % SPINNER_FRAMES.len()]
+}
+
+fn render_loading(f: &mut Frame, area: Rect, tick: usize, theme: &Theme) {
+    let loading = Paragraph::new(Line::from(vec![
+        Span::styled(spinner(tick), Style::default().fg(theme.strong)),
+        Span::raw(" "),
+        Span::styled("Loading articles...", Style::default().fg(theme.text)),
+    ]));
     f.render_widget(loading, area);
 }
 
-fn render_error(f: &mut Frame, area: Rect, msg: &str) {
+fn render_error(f: &mut Frame, area: Rect, error: &ApiError, theme: &Theme, keymap: &Keymap) {
     let error = Paragraph::new(vec![
         Line::from(Span::styled(
-            "Failed to load articles",
+            error.title(),
             Style::default()
-                .fg(Color::White)
+                .fg(theme.strong)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
-        Line::from(Span::styled(msg, Style::default().fg(Color::DarkGray))),
+        Line::from(Span::styled(
+            error.to_string(),
+            Style::default().fg(theme.muted),
+        )),
         Line::from(""),
-        Line::from(Span::raw("Press 'r' to retry or 'q' to quit")),
-    ]);
+        Line::from(Span::styled(error.hint(), Style::default().fg(theme.text))),
+        Line::from(""),
+        Line::from(Span::raw(format!(
+            "Press '{}' to retry or '{}' to quit",
+            keymap.key(Action::Refresh).label(),
+            keymap.key(Action::Quit).label()
+        ))),
+    ])
+    .wrap(Wrap { trim: true });
     f.render_widget(error, area);
 }
 
+fn render_new_articles_banner(
+    f: &mut Frame,
+    area: Rect,
+    count: usize,
+    theme: &Theme,
+    keymap: &Keymap,
+) {
+    let noun = if count == 1 { "article" } else { "articles" };
+    let banner = Paragraph::new(Line::from(vec![
+        Span::styled(
+            format!(" {} new {} ", count, noun),
+            Style::default()
+                .fg(theme.inverse)
+                .bg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" — press {} to show", keymap.key(Action::Refresh).label()),
+            Style::default().fg(theme.warning),
+        ),
+    ]));
+    f.render_widget(banner, area);
+}
+
 fn render_article_list(f: &mut Frame, area: Rect, app: &mut App) {
-    // Calculate content width as 85% of terminal width, with minimum of 20
-    let content_width = ((area.width as f32) * 0.85).max(20.0) as usize;
+    let theme = app.theme;
+    if app.articles.is_empty() {
+        let key = |action| app.keymap.key(action).label();
+        let empty = match app.mode {
+            ViewMode::Saved => Some((
+                "No saved articles".to_string(),
+                format!(
+                    "Press {} on an article to save it for later.",
+                    key(Action::ToggleBookmark)
+                ),
+            )),
+            ViewMode::Search if app.search_results_query.is_empty() => Some((
+                "No search results".to_string(),
+                format!(
+                    "Press {} and Enter to search every loaded, cached and saved article.",
+                    key(Action::Search)
+                ),
+            )),
+            ViewMode::Search => Some((
+                "No search results".to_string(),
+                format!("Nothing matches \"{}\".", app.search_results_query),
+            )),
+            _ if app.offline => Some((
+                "No cached articles".to_string(),
+                "Run ptlk once without --offline to fill the cache.".to_string(),
+            )),
+            _ => None,
+        };
+        if let Some((title, hint)) = empty {
+            render_empty(f, area, &title, &hint, &theme);
+            return;
+        }
+    }
+
+    let [sticky_area, area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+
+    let content_width = area.width as usize;
+
+    // The top visible article's section is named by the sticky header instead
+    // of inline, so settle the scroll offset before the list does
+    let mut items = list_items(app, content_width);
+    let top = list_offset(app, &items, area.height as usize);
+    if let Some(article) = app.articles.get(top) {
+        items[top] = create_list_item(article, None, content_width, app);
+    }
+    *app.list_state.offset_mut() = top;
+
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .bg(theme.selected_bg)
+            .fg(theme.selected_fg)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    f.render_stateful_widget(list, area, &mut app.list_state);
 
-    let mut items: Vec<ListItem> = app
+    // Sticky header for the section at the top of the viewport
+    if let Some(article) = app.articles.get(app.list_state.offset()) {
+        let title = app.section_title(article);
+        f.render_widget(Paragraph::new(section_header(&title, &theme)), sticky_area);
+    }
+}
+
+/// Where the list has to start for the selected row to fit in `height` rows,
+/// scrolling as little as possible from the current offset, like `List` does.
+/// The top article goes without its section header.
+fn list_offset(app: &App, items: &[ListItem], height: usize) -> usize {
+    let offset = app.list_state.offset();
+    let Some(selected) = app.list_state.selected() else {
+        return offset;
+    };
+    let selected = selected.min(items.len().saturating_sub(1));
+    let rows = |top: usize| -> usize {
+        let header = if top > 0 && app.is_section_start(top) {
+            SECTION_HEADER_ROWS
+        } else {
+            0
+        };
+        items[top..=selected]
+            .iter()
+            .map(ListItem::height)
+            .sum::<usize>()
+            - header
+    };
+
+    let mut top = offset.min(selected);
+    while top < selected && rows(top) > height {
+        top += 1;
+    }
+    top
+}
+
+/// Rows of the article list, with a section header before each section but
+/// the first, plus a trailing row
+fn list_items(app: &App, content_width: usize) -> Vec<ListItem<'static>> {
+    let theme = app.theme;
+    let mut items: Vec<ListItem<'static>> = app
         .articles
         .iter()
         .enumerate()
         .map(|(i, article)| {
-            let is_expanded = app.expanded.contains(&i);
-            create_list_item(article, is_expanded, content_width)
+            let header = (i != 0 && app.is_section_start(i)).then(|| app.section_title(article));
+            create_list_item(article, header.as_deref(), content_width, app)
         })
         .collect();
 
-    // Add Website Link item
-    let url = &app.client.base_url;
-    items.push(ListItem::new(Text::from(vec![
-        Line::from(""),
-        Line::from(vec![
-            Span::raw("   "),
-            Span::raw("Want more? Visit "),
-            Span::styled(
-                format!("{} ↗", url),
-                Style::default()
-                    .fg(Color::Blue)
-                    .add_modifier(Modifier::UNDERLINED),
-            ),
-        ]),
+    if app.has_more {
+        // Placeholder row while the next page is fetched
+        items.push(ListItem::new(Text::from(vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("   "),
+                Span::styled(
+                    "Loading older articles...",
+                    Style::default().fg(theme.muted),
+                ),
+            ]),
+        ])));
+    } else {
+        // Add Website Link item
+        let url = &app.client.base_url;
+        items.push(ListItem::new(Text::from(vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("   "),
+                Span::raw("Want more? Visit "),
+                Span::styled(
+                    format!("{} ↗", url),
+                    Style::default()
+                        .fg(theme.link)
+                        .add_modifier(Modifier::UNDERLINED),
+                ),
+            ]),
+            Line::from(""),
+        ])));
+    }
+    items
+}
+
+/// Where the article goes inside the detail pane
+fn detail_inner(area: Rect) -> Rect {
+    let inner = Block::default().borders(Borders::LEFT).inner(area);
+    // One column of padding after the border
+    let [_, inner] = Layout::horizontal([Constraint::Length(1), Constraint::Min(0)]).areas(inner);
+    inner
+}
+
+/// The selected article in full, beside the list
+fn render_detail_pane(f: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(Style::default().fg(app.theme.muted));
+    f.render_widget(block, area);
+    let inner = detail_inner(area);
+
+    let Some(article) = app.selected_article() else {
+        return;
+    };
+    let lines = detail_lines(article, inner.width as usize, app);
+    // Keep the last line reachable but don't scroll past it
+    let max_scroll = (lines.len() as u16).saturating_sub(inner.height);
+    let scroll = app.detail_scroll.min(max_scroll);
+    f.render_widget(Paragraph::new(lines).scroll((scroll, 0)), inner);
+}
+
+fn detail_lines(article: &Article, width: usize, app: &App) -> Vec<Line<'static>> {
+    let theme = app.theme;
+    let title_style = Style::default()
+        .fg(theme.strong)
+        .add_modifier(Modifier::BOLD);
+    // Highlight before wrapping so a match across the wrap stays highlighted
+    let title = markdown::highlight(
+        vec![(article.title.clone(), title_style)],
+        &app.search,
+        &theme,
+    );
+    let mut lines: Vec<Line> = markdown::wrap(&title, width)
+        .into_iter()
+        .map(Line::from)
+        .collect();
+    let mut meta = vec![
+        Span::styled(
+            format!("[{}]", article.tag),
+            get_tag_style(&article.tag, &theme),
+        ),
+        Span::raw(" "),
+    ];
+    if app.is_bookmarked(article) {
+        meta.push(Span::styled("★ ", Style::default().fg(theme.warning)));
+    }
+    meta.push(Span::styled(
+        format!(
+            "{} • {}",
+            article.source,
+            format_time(&article.published_at)
+        ),
+        Style::default().fg(theme.muted),
+    ));
+    lines.push(Line::from(meta));
+    lines.push(Line::from(""));
+    lines.extend(article_body(article, width, "", app));
+    lines
+}
+
+/// A bold title and a muted hint in place of an empty list
+fn render_empty(f: &mut Frame, area: Rect, title: &str, hint: &str, theme: &Theme) {
+    let empty = Paragraph::new(vec![
+        Line::from(Span::styled(
+            title.to_string(),
+            Style::default()
+                .fg(theme.strong)
+                .add_modifier(Modifier::BOLD),
+        )),
         Line::from(""),
-    ])));
+        Line::from(Span::styled(
+            hint.to_string(),
+            Style::default().fg(theme.muted),
+        )),
+    ]);
+    f.render_widget(empty, area);
+}
 
-    let list = List::new(items).highlight_style(
+fn section_header(title: &str, theme: &Theme) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("── {} ──", title),
         Style::default()
-            .bg(Color::Gray)
-            .fg(Color::Black)
+            .fg(theme.muted)
             .add_modifier(Modifier::BOLD),
-    );
-
-    f.render_stateful_widget(list, area, &mut app.list_state);
+    ))
 }
 
+/// One article row; read articles are dimmed
 fn create_list_item(
-    article: &crate::api::Article,
-    is_expanded: bool,
+    article: &Article,
+    header: Option<&str>,
     width: usize,
+    app: &App,
 ) -> ListItem<'static> {
+    let (theme, query) = (&app.theme, app.search.as_str());
     let mut lines = vec![];
 
-    let tag_style = get_tag_style(&article.tag);
+    // SECTION_HEADER_ROWS rows: a blank line and the title
+    if let Some(header) = header {
+        lines.push(Line::from(""));
+        lines.push(section_header(header, theme));
+    }
 
-    lines.push(Line::from(vec![
+    let (tag_style, title_style) = if app.is_read(article) {
+        let dimmed = Style::default().fg(theme.muted);
+        (dimmed, dimmed)
+    } else {
+        (
+            get_tag_style(&article.tag, theme),
+            Style::default().add_modifier(Modifier::BOLD),
+        )
+    };
+
+    let mut title = vec![
         Span::styled(format!("[{}]", article.tag), tag_style),
         Span::raw(" "),
-        Span::styled(
-            article.title.clone(),
-            Style::default().add_modifier(Modifier::BOLD),
-        ),
-    ]));
+    ];
+    if app.is_bookmarked(article) {
+        title.push(Span::styled("★ ", Style::default().fg(theme.warning)));
+    }
+    title.extend(
+        markdown::highlight(vec![(article.title.clone(), title_style)], query, theme)
+            .into_iter()
+            .map(|(text, style)| Span::styled(text, style)),
+    );
+    lines.push(Line::from(title));
 
-    lines.push(Line::from(vec![
-        Span::raw("   "),
-        Span::styled(
-            format!(
-                "{} • {}",
-                article.source,
-                format_time(&article.published_at)
-            ),
-            Style::default().fg(Color::DarkGray),
-        ),
-    ]));
+    let meta_style = Style::default().fg(theme.muted);
+    let mut meta = vec![Span::raw("   ")];
+    meta.extend(
+        markdown::highlight(vec![(article.source.clone(), meta_style)], query, theme)
+            .into_iter()
+            .map(|(text, style)| Span::styled(text, style)),
+    );
+    meta.push(Span::styled(
+        format!(" • {}", format_time(&article.published_at)),
+        meta_style,
+    ));
+    lines.push(Line::from(meta));
 
-    if is_expanded {
+    // The detail pane shows the body instead when the layout is split
+    if app.is_expanded(article) && !app.split_active {
         lines.push(Line::from(""));
+        lines.extend(article_body(article, width, "   ", app));
+    }
 
-        if article.summary.is_empty() {
-            lines.push(Line::from(vec![
-                Span::raw("   "),
-                Span::styled(
-                    "No summary available",
-                    Style::default()
-                        .fg(Color::DarkGray)
-                        .add_modifier(Modifier::ITALIC),
-                ),
-            ]));
-        } else {
-            for line in wrap_text(&article.summary, width.saturating_sub(3)) {
-                lines.push(Line::from(vec![
-                    Span::raw("   "),
-                    Span::styled(line, Style::default().fg(Color::Gray)),
-                ]));
-            }
-        }
+    ListItem::new(Text::from(lines))
+}
 
-        lines.push(Line::from(""));
-        for line in wrap_text(&format!("URL: {}", article.url), width.saturating_sub(3)) {
-            lines.push(Line::from(vec![
-                Span::raw("   "),
-                Span::styled(
-                    line,
-                    Style::default()
-                        .fg(Color::DarkGray)
-                        .add_modifier(Modifier::UNDERLINED),
-                ),
-            ]));
-        }
+/// Summary, URL and open hint of an expanded article, wrapped to `width`
+/// including `indent`
+fn article_body(
+    article: &Article,
+    width: usize,
+    indent: &'static str,
+    app: &App,
+) -> Vec<Line<'static>> {
+    let theme = &app.theme;
+    let width = width.saturating_sub(indent.len());
+    let mut lines = vec![];
+
+    if article.summary.is_empty() {
         lines.push(Line::from(vec![
-            Span::raw("   "),
+            Span::raw(indent),
             Span::styled(
-                "Press Enter to open in browser",
-                Style::default().fg(Color::White),
+                "No summary available",
+                Style::default()
+                    .fg(theme.muted)
+                    .add_modifier(Modifier::ITALIC),
             ),
         ]));
+    } else {
+        for mut line in markdown::render(&article.summary, width, &app.search, theme) {
+            line.spans.insert(0, Span::raw(indent));
+            lines.push(line);
+        }
     }
 
-    ListItem::new(Text::from(lines))
+    lines.push(Line::from(""));
+    for line in wrap_text(&format!("URL: {}", article.url), width) {
+        lines.push(Line::from(vec![
+            Span::raw(indent),
+            Span::styled(
+                line,
+                Style::default()
+                    .fg(theme.muted)
+                    .add_modifier(Modifier::UNDERLINED),
+            ),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        Span::raw(indent),
+        Span::styled(
+            format!(
+                "Press {} to open in browser",
+                app.keymap.key(Action::Open).label()
+            ),
+            Style::default().fg(theme.strong),
+        ),
+    ]));
+    lines
 }
 
-fn get_tag_style(_tag: &str) -> Style {
-    Style::default().fg(Color::Gray)
+fn get_tag_style(_tag: &str, theme: &Theme) -> Style {
+    Style::default().fg(theme.text)
 }
 
-fn format_time(iso_time: &str) -> String {
+pub fn format_time(iso_time: &str) -> String {
     if iso_time.len() >= 16 {
         let date = &iso_time[0..10];
         let time = &iso_time[11..16];
@@ -233,8 +617,7 @@ fn wrap_text(text: &str, width: usize) -> Vec<String> {
         let mut current_width = 0;
 
         for ch in paragraph.chars() {
-            // Calculate display width: CJK characters take 2 columns
-            let char_width = if is_wide_char(ch) { 2 } else { 1 };
+            let char_width = char_width(ch);
 
             if current_width + char_width > width && !current_line.is_empty() {
                 lines.push(current_line);
@@ -254,6 +637,20 @@ fn wrap_text(text: &str, width: usize) -> Vec<String> {
     lines
 }
 
+/// Terminal columns taken by `text`, counting wide characters as 2
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+/// Terminal columns taken by `ch`: 2 for CJK and other wide characters
+pub fn char_width(ch: char) -> usize {
+    if is_wide_char(ch) {
+        2
+    } else {
+        1
+    }
+}
+
 /// Check if a character is a wide character (CJK, fullwidth, etc.)
 fn is_wide_char(ch: char) -> bool {
     let cp = ch as u32;
@@ -272,44 +669,320 @@ fn is_wide_char(ch: char) -> bool {
     )
 }
 
-fn render_footer(f: &mut Frame, area: Rect) {
-    let help_text = vec![
-        Span::styled("j/↓", Style::default().fg(Color::White)),
-        Span::raw(" Down "),
-        Span::styled("k/↑", Style::default().fg(Color::White)),
-        Span::raw(" Up "),
-        Span::styled("Enter", Style::default().fg(Color::White)),
-        Span::raw(" Open "),
-        Span::styled("Space", Style::default().fg(Color::White)),
-        Span::raw(" Toggle "),
-        Span::styled("q", Style::default().fg(Color::White)),
-        Span::raw(" Quit"),
+fn render_footer(f: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let mut help_text = vec![
+        Span::styled(
+            format!("[{}]", app.mode.label()),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" {} ", app.count_label()),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled(
+            format!("↕ {} ", app.sort.label()),
+            Style::default().fg(theme.muted),
+        ),
     ];
 
+    if app.is_fetching() && app.loading_state == LoadingState::Loaded {
+        let label = if app.loading_more {
+            "Loading more"
+        } else {
+            "Refreshing"
+        };
+        help_text.push(Span::styled(
+            format!("{} {} ", spinner(app.tick), label),
+            Style::default().fg(theme.text),
+        ));
+    }
+
+    if let Some(reason) = &app.stale_reason {
+        help_text.push(Span::styled(
+            format!("stale: {} ", reason),
+            Style::default().fg(theme.warning),
+        ));
+    }
+
+    if let Some(notice) = app.client.retry_notice() {
+        help_text.push(Span::styled(
+            format!(
+                "{}, retrying ({}/{}) in {:.1}s ",
+                notice.reason,
+                notice.attempt,
+                notice.max_retries,
+                notice.remaining().as_secs_f32()
+            ),
+            Style::default().fg(theme.warning),
+        ));
+    }
+
+    if !app.filters.is_empty() {
+        help_text.push(Span::styled(
+            format!("filter: {} ", app.filters.label()),
+            Style::default().fg(theme.link),
+        ));
+    }
+
+    if let Some(prompt) = &app.search_prompt {
+        help_text.push(Span::styled(
+            format!("/{}", prompt.input),
+            Style::default().fg(theme.strong),
+        ));
+        help_text.push(Span::styled("█ ", Style::default().fg(theme.text)));
+        match &prompt.error {
+            Some(error) => help_text.push(Span::styled(
+                format!("{} ", error),
+                Style::default().fg(theme.error),
+            )),
+            None if !prompt.input.is_empty() => help_text.push(Span::styled(
+                format!("{} ", app.search_position()),
+                Style::default().fg(theme.muted),
+            )),
+            None => {}
+        }
+    } else if !app.search.is_empty() {
+        help_text.push(Span::styled(
+            format!("/{} ", app.search),
+            Style::default().fg(theme.strong),
+        ));
+        help_text.push(Span::styled(
+            format!("{} ", app.search_position()),
+            Style::default().fg(theme.muted),
+        ));
+    }
+
+    let key = |action| app.keymap.key(action).label();
+    let hints = [
+        (format!("{}/↓", key(Action::Down)), "Down"),
+        (format!("{}/↑", key(Action::Up)), "Up"),
+        (key(Action::Open), "Open"),
+        (key(Action::ToggleExpand), "Toggle"),
+        (
+            format!(
+                "{}/{}",
+                key(Action::PreviousSection),
+                key(Action::NextSection)
+            ),
+            "Day",
+        ),
+        (key(Action::ToggleMode), "Latest/Timeline"),
+        (format!("1-{}", Tab::ALL.len()), "Tabs"),
+        (key(Action::GoToDate), "Date"),
+        (key(Action::Search), "Search"),
+        (key(Action::Filter), "Filter"),
+        (key(Action::Sort), "Sort"),
+        (key(Action::ToggleRead), "Read"),
+        (key(Action::ToggleBookmark), "Save"),
+        (key(Action::ToggleSaved), "Saved"),
+        (key(Action::ToggleSplit), "Split"),
+        (key(Action::ToggleLog), "Log"),
+        (key(Action::Quit), "Quit"),
+    ];
+    for (i, (key, label)) in hints.into_iter().enumerate() {
+        let separator = if i == 0 { "" } else { " " };
+        help_text.push(Span::raw(separator));
+        help_text.push(Span::styled(key, Style::default().fg(theme.strong)));
+        help_text.push(Span::raw(format!(" {}", label)));
+    }
+
     let footer = Paragraph::new(Line::from(help_text));
     f.render_widget(footer, area);
 }
 
-fn render_error_popup(f: &mut Frame, error: &str) {
+/// The latest debug log entries, newest at the bottom
+fn render_log_pane(f: &mut Frame, area: Rect, theme: &Theme) {
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(theme.muted));
+
+    let Some(logger) = logging::logger() else {
+        let block = block.title("Log");
+        let hint = Paragraph::new(Span::styled(
+            "Logging is off. Run ptlk with --debug to record requests and key events.",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block);
+        f.render_widget(hint, area);
+        return;
+    };
+
+    let block = block.title(format!("Log — {}", logger.path().display()));
+    let height = block.inner(area).height as usize;
+    let lines: Vec<Line> = logger
+        .recent(height)
+        .iter()
+        .map(|entry| {
+            let color = match entry.level {
+                Level::Error => theme.error,
+                Level::Warn => theme.warning,
+                Level::Info => theme.text,
+                Level::Debug => theme.muted,
+            };
+            Line::from(Span::styled(entry.summary(), Style::default().fg(color)))
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_date_prompt(f: &mut Frame, prompt: &Prompt, theme: &Theme) {
     let area = f.area();
-    let popup_width = 50.min(area.width - 4);
-    let popup_height = 5;
+    let popup_width = 50.min(area.width.saturating_sub(4));
+    let popup_height = 5.min(area.height);
 
     let popup_area = Rect::new(
-        (area.width - popup_width) / 2,
-        (area.height - popup_height) / 2,
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
         popup_width,
         popup_height,
     );
 
-    let popup = Paragraph::new(error)
-        .style(Style::default().fg(Color::White))
-        .wrap(Wrap { trim: true })
+    let status = match &prompt.error {
+        Some(error) => Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(theme.error),
+        )),
+        None => Line::from(Span::styled(
+            "YYYY-MM-DD or YYYY-MM-DD..YYYY-MM-DD",
+            Style::default().fg(theme.muted),
+        )),
+    };
+
+    let popup = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(theme.text)),
+            Span::styled(prompt.input.clone(), Style::default().fg(theme.strong)),
+            Span::styled("█", Style::default().fg(theme.text)),
+        ]),
+        Line::from(""),
+        status,
+    ])
+    .block(
+        Block::default()
+            .title("Go to date")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.text)),
+    );
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(popup, popup_area);
+}
+
+/// Every tag and source with its count; `+` includes, `-` excludes
+fn render_facet_picker(f: &mut Frame, picker: &mut FacetPicker, theme: &Theme) {
+    let area = f.area();
+    let popup_width = 50.min(area.width.saturating_sub(4));
+    let popup_height =
+        (picker.facets.len() as u16 + 3).clamp(5, area.height.saturating_sub(4).max(5));
+
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height.min(area.height),
+    );
+
+    let block = Block::default()
+        .title("Filter by tag or source")
+        .title_bottom(Line::from(vec![
+            Span::styled(" Space", Style::default().fg(theme.strong)),
+            Span::raw(" +/- "),
+            Span::styled("c", Style::default().fg(theme.strong)),
+            Span::raw(" Clear "),
+            Span::styled("Enter", Style::default().fg(theme.strong)),
+            Span::raw(" Apply "),
+            Span::styled("Esc", Style::default().fg(theme.strong)),
+            Span::raw(" Cancel "),
+        ]))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.text));
+
+    f.render_widget(Clear, popup_area);
+
+    if picker.facets.is_empty() {
+        let empty = Paragraph::new(Span::styled(
+            "No articles loaded",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block);
+        f.render_widget(empty, popup_area);
+        return;
+    }
+
+    // Name column width: what's left after the marker, kind and count columns
+    let name_width = (block.inner(popup_area).width as usize).saturating_sub(3 + 7 + 6);
+    let items: Vec<ListItem> = picker
+        .facets
+        .iter()
+        .map(|facet| {
+            let (marker, marker_style) = match facet.selection {
+                Selection::Any => (" ", Style::default()),
+                Selection::Include => ("+", Style::default().fg(theme.link)),
+                Selection::Exclude => ("-", Style::default().fg(theme.error)),
+            };
+            let name = wrap_text(&facet.name, name_width)
+                .into_iter()
+                .next()
+                .unwrap_or_default();
+            let padding = name_width.saturating_sub(display_width(&name));
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(" {} ", marker),
+                    marker_style.add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:<7}", facet.kind.label()),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(
+                    format!("{}{}", name, " ".repeat(padding)),
+                    Style::default().fg(theme.text),
+                ),
+                Span::styled(
+                    format!("{:>6}", facet.count),
+                    Style::default().fg(theme.muted),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(block).highlight_style(
+        Style::default()
+            .bg(theme.selected_bg)
+            .fg(theme.selected_fg)
+            .add_modifier(Modifier::BOLD),
+    );
+    f.render_stateful_widget(list, popup_area, &mut picker.list_state);
+}
+
+fn render_error_popup(f: &mut Frame, error: &str, theme: &Theme) {
+    let area = f.area();
+    let popup_width = 50.min(area.width.saturating_sub(4));
+    // Every line of the error, wrapped inside the borders
+    let lines: Vec<Line> = error
+        .lines()
+        .flat_map(|line| wrap_text(line, popup_width.saturating_sub(2) as usize))
+        .map(Line::from)
+        .collect();
+    let popup_height = (lines.len() as u16 + 2).min(area.height);
+
+    let popup_area = Rect::new(
+        area.width.saturating_sub(popup_width) / 2,
+        area.height.saturating_sub(popup_height) / 2,
+        popup_width,
+        popup_height,
+    );
+
+    f.render_widget(Clear, popup_area);
+    let popup = Paragraph::new(lines)
+        .style(Style::default().fg(theme.strong))
         .block(
             Block::default()
                 .title("Error")
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Gray)),
+                .border_style(Style::default().fg(theme.text)),
         );
 
     f.render_widget(popup, popup_area);
@@ -319,19 +992,204 @@ fn render_error_popup(f: &mut Frame, error: &str) {
 mod tests {
     use super::*;
     use crate::app::App;
+    use crate::test_support::article;
     use ratatui::{backend::TestBackend, Terminal};
 
+    /// Draw one frame of `app` and return the screen as a string
+    fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|f| render(f, app)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
     #[test]
     fn test_render_does_not_panic() {
-        let backend = TestBackend::new(80, 24);
-        let mut terminal = Terminal::new(backend).unwrap();
         let mut app = App::new("http://localhost:3000", 50);
+        render_to_string(&mut app, 80, 24);
+    }
 
-        terminal
-            .draw(|f| {
-                render(f, &mut app);
+    #[test]
+    fn test_render_section_headers() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.articles = vec![
+            Article {
+                briefing_date: "2026-10-16".to_string(),
+                ..article("https://example.com/1")
+            },
+            article("https://example.com/2"),
+        ];
+        app.loading_state = LoadingState::Loaded;
+
+        let content = render_to_string(&mut app, 80, 30);
+        assert!(content.contains("2026-10-16 briefing"));
+        assert!(content.contains("2026-10-15 briefing"));
+    }
+
+    #[test]
+    fn test_render_sticky_header_after_jump() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.articles = (1..=9)
+            .map(|day| Article {
+                briefing_date: format!("2026-10-0{}", day),
+                ..article(&format!("https://example.com/{}", day))
             })
-            .unwrap();
+            .collect();
+        app.list_state.select(Some(0));
+        app.loading_state = LoadingState::Loaded;
+        render_to_string(&mut app, 80, 30);
+
+        // Every article starts a section; the one scrolled to the top only gets
+        // the sticky header
+        app.go_to_last();
+        let content = render_to_string(&mut app, 80, 30);
+        let top = &app.articles[app.list_state.offset()].briefing_date;
+        assert_eq!(content.matches(&format!("{} briefing", top)).count(), 1);
+        assert!(content.contains("2026-10-09 briefing"));
+        assert!(content.contains("Title https://example.com/9"));
+
+        // Scrolled no further than the selected row needs, counting the top
+        // article without its header
+        let rows = 30 - 8 - 1 - 1 - 1;
+        let items = list_items(&app, 80);
+        let top = app.list_state.offset();
+        let shown: usize = items[top..=8].iter().map(ListItem::height).sum();
+        assert!(shown - SECTION_HEADER_ROWS <= rows);
+        let one_up: usize = items[top - 1..=8].iter().map(ListItem::height).sum();
+        assert!(one_up - SECTION_HEADER_ROWS > rows);
+    }
+
+    #[test]
+    fn test_render_error_shows_hint() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.loading_state = LoadingState::Error(ApiError::RateLimited { retry_after: None });
+
+        let content = render_to_string(&mut app, 80, 24);
+        assert!(content.contains("Too many requests"));
+        assert!(content.contains("Wait a minute before retrying."));
+    }
+
+    #[test]
+    fn test_render_log_pane() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.show_log = true;
+
+        let content = render_to_string(&mut app, 80, 30);
+        assert!(content.contains("Log"));
+        assert!(content.contains("Logging is off"));
+    }
+
+    #[test]
+    fn test_render_facet_picker_and_filter_status() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.loading_state = LoadingState::Loaded;
+        app.filters.exclude_tags = vec!["Crypto".to_string()];
+        app.open_facet_picker();
+
+        let content = render_to_string(&mut app, 80, 30);
+        assert!(content.contains("Filter by tag or source"));
+        assert!(content.contains(" - Tag    Crypto"));
+        assert!(content.contains("filter: -tag:Crypto"));
+    }
+
+    #[test]
+    fn test_render_unread_count() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.articles = vec![article("https://example.com/1")];
+        app.loading_state = LoadingState::Loaded;
+
+        assert!(render_to_string(&mut app, 80, 30).contains("1 unread"));
+
+        app.mark_all_read();
+        assert!(render_to_string(&mut app, 80, 30).contains("0 unread"));
+    }
+
+    #[test]
+    fn test_render_saved_count() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.articles = vec![article("https://example.com/1")];
+        app.list_state.select(Some(0));
+        app.loading_state = LoadingState::Loaded;
+        assert!(!render_to_string(&mut app, 80, 30).contains("saved"));
+
+        app.toggle_bookmark();
+        assert!(render_to_string(&mut app, 80, 30).contains("1 unread · 1 saved"));
+    }
+
+    #[test]
+    fn test_render_error_popup() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.last_error = Some(format!(
+            "Failed to parse read.json: {}\nFailed to parse bookmarks.json",
+            "expected value at line 1 column 1 ".repeat(3)
+        ));
+        let text = render_to_string(&mut app, 80, 30);
+        assert!(text.contains("Failed to parse bookmarks.json"));
+
+        // Smaller than the popup
+        render_to_string(&mut app, 3, 2);
+    }
+
+    #[test]
+    fn test_render_body_fills_list_width() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.articles = vec![Article {
+            summary: "word ".repeat(60),
+            ..article("https://example.com/1")
+        }];
+        app.list_state.select(Some(0));
+        app.loading_state = LoadingState::Loaded;
+        app.toggle_expand();
+
+        // Not 85 of the 100 columns
+        let text = render_to_string(&mut app, 100, 30);
+        let widest = text
+            .lines()
+            .filter(|line| line.contains("word"))
+            .map(|line| line.trim_end().chars().count())
+            .max();
+        assert!(widest > Some(90), "{:?}", widest);
+    }
+
+    #[test]
+    fn test_render_tab_bar() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.loading_state = LoadingState::Loaded;
+        assert!(render_to_string(&mut app, 80, 30)
+            .contains(" 1 Latest   2 Timeline   3 Saved   4 Search "));
+
+        app.search_results_query = "rust".to_string();
+        assert!(render_to_string(&mut app, 80, 30).contains("4 Search: rust"));
+    }
+
+    #[test]
+    fn test_render_split_detail_pane() {
+        let mut app = App::new("http://localhost:3000", 50);
+        app.articles = vec![Article {
+            summary: "Only shown in the detail pane".to_string(),
+            ..article("https://example.com/1")
+        }];
+        app.list_state.select(Some(0));
+        app.loading_state = LoadingState::Loaded;
+
+        app.set_terminal_width(160);
+        let text = render_to_string(&mut app, 160, 30);
+        assert!(text.contains("Only shown in the detail pane"));
+        assert!(text.contains("URL: https://example.com/1"));
+        // Seven lines of article: all fit in 30 rows, four in 14
+        assert_eq!(max_detail_scroll(&app, Rect::new(0, 0, 160, 30)), 0);
+        assert_eq!(max_detail_scroll(&app, Rect::new(0, 0, 160, 14)), 3);
+
+        // Too narrow for two panes
+        app.set_terminal_width(100);
+        let text = render_to_string(&mut app, 100, 30);
+        assert!(!text.contains("Only shown in the detail pane"));
+        assert_eq!(max_detail_scroll(&app, Rect::new(0, 0, 100, 14)), 0);
     }
 
     #[test]